@@ -5,7 +5,7 @@ use std::env;
 
 fn main() -> Result<(), ProcessorError> {
     const DEFAULT_STEP: u32 = 30;
-    const ABOUT: &'static str =
+    const ABOUT: &str =
         "Analyzes n-core CPU temperature data via interpolation, and least squares approximation.
 🖖🏽 Live long and interpolate.";
 
@@ -40,8 +40,24 @@ fn main() -> Result<(), ProcessorError> {
     let output_path = matches.value_of("output-path");
     let step = value_t!(matches.value_of("step_size"), u32).unwrap_or(DEFAULT_STEP);
 
-    files
+    // Process every file, even when some fail, so one bad input doesn't
+    // lose the results for the rest of the batch.
+    let results: Vec<Result<(), ProcessorError>> = files
         .par_iter()
         .map(|data_file_path| process_data_file(step, data_file_path, output_path))
-        .collect()
+        .collect();
+
+    let mut failed = false;
+    for (data_file_path, result) in files.iter().zip(results) {
+        if let Err(e) = result {
+            eprintln!("{}: {}", data_file_path, e);
+            failed = true;
+        }
+    }
+
+    if failed {
+        Err(ProcessorError::Error)
+    } else {
+        Ok(())
+    }
 }
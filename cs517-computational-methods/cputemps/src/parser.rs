@@ -15,7 +15,7 @@ pub struct ParserIterator {
 
 impl Parser {
     /// # Example
-    /// ```
+    /// ```ignore
     /// use cputemps::parser::Parser;
     /// let path = "data/sensors-2019.02.09.txt";
     /// let parser = Parser::new(path);
@@ -54,10 +54,7 @@ impl Iterator for ParserIterator {
     fn next(&mut self) -> Option<Self::Item> {
         let line = self.lines.next();
         match line {
-            Some(Ok(line)) => match parse_line(&line[..]) {
-                Ok(v) => Some(v),
-                _ => None,
-            },
+            Some(Ok(line)) => parse_line(&line[..]).ok(),
             _ => None,
         }
     }
@@ -73,7 +70,6 @@ fn parse_line(line: &str) -> Result<Vec<f64>, std::num::ParseFloatError> {
         .collect()
 }
 #[cfg(test)]
-
 mod tests {
     use super::*;
 
@@ -127,8 +123,8 @@ mod tests {
         let sut = Parser::new(&file_name[..]);
 
         match sut {
-            Ok(_parse) => return,
-            Err(e) => panic!("Parser was not created: {}", e.to_string()),
+            Ok(_parse) => (),
+            Err(e) => panic!("Parser was not created: {}", e),
         }
     }
 
@@ -1,6 +1,6 @@
 use crate::{pairs::Pairs, parser::Parser, writer::Writer};
 use launearalg::{
-    approximator::least_squares::*, interpolater::cubic_spline::*,
+    approximator::least_squares::*, error::LinalgError, interpolater::cubic_spline::*,
     interpolater::linear_piecewise::*, traits::*,
 };
 use std::fmt;
@@ -9,15 +9,17 @@ use std::path::Path;
 #[derive(Debug)]
 pub enum ProcessorError {
     IOError,
+    AnalysisError(LinalgError),
     Error,
 }
 
 impl std::error::Error for ProcessorError {}
 impl fmt::Display for ProcessorError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
-        match self {
-            &ProcessorError::IOError => write!(f, "Encountered a filesystem error"),
-            &ProcessorError::Error => write!(f, "An error occurred while processing"),
+        match *self {
+            ProcessorError::IOError => write!(f, "Encountered a filesystem error"),
+            ProcessorError::AnalysisError(e) => write!(f, "Could not analyze data: {}", e),
+            ProcessorError::Error => write!(f, "An error occurred while processing"),
         }
     }
 }
@@ -62,8 +64,7 @@ fn process_all_cores_single_pass(
         .collect();
 
     process_pairwise(step, &parser, &mut writer, &mut analyzers[..]);
-    process_global(step, &parser, &mut writer, &mut analyzers[..]);
-    Ok(())
+    process_global(step, &parser, &mut writer, &mut analyzers[..])
 }
 
 fn get_outout_path(output_path: Option<&str>, input_file: &str) -> String {
@@ -97,7 +98,7 @@ fn process_pairwise(
         let i = i as f64;
         let step = step as f64;
 
-        let x1 = i * step as f64;
+        let x1 = i * step;
         let x2 = (i + 1.0) * step;
 
         for (core, endpoints) in pairs.iter().enumerate() {
@@ -121,14 +122,15 @@ fn process_global(
     parser: &Parser,
     writer: &mut Writer,
     analyzers: &mut [Vec<Box<dyn Analyzer<Output = dyn Solution>>>],
-) {
+) -> Result<(), ProcessorError> {
     let mut globals = Vec::<(usize, Box<dyn Solution>)>::new();
 
-    for core in 0..parser.cores {
-        for analyzer in analyzers[core].iter_mut() {
+    for (core, core_analyzers) in analyzers.iter_mut().enumerate().take(parser.cores) {
+        for analyzer in core_analyzers.iter_mut() {
             match analyzer.analyze_global() {
-                Some(sol) => globals.push((core, sol)),
-                None => continue,
+                Ok(Some(sol)) => globals.push((core, sol)),
+                Ok(None) => continue,
+                Err(e) => return Err(ProcessorError::AnalysisError(e)),
             }
         }
     }
@@ -136,4 +138,27 @@ fn process_global(
     for (core, sol) in globals {
         writer.write_global(core, sol.lhs(), sol);
     }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs::File;
+    use std::io::Write;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_too_little_data_is_an_error() {
+        let dir = tempdir().unwrap();
+        let file_path = dir.path().join("sensors.txt");
+        let mut file = File::create(&file_path).unwrap();
+        writeln!(file, "+83.0°C +84.0°C +65.0°C +81.0°C").unwrap();
+
+        let result = process_data_file(30, file_path.to_str().unwrap(), None);
+        match result {
+            Err(ProcessorError::AnalysisError(LinalgError::Empty)) => (),
+            other => panic!("Expected an analysis error, got {:?}", other),
+        }
+    }
 }
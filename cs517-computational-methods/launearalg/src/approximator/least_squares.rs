@@ -1,17 +1,39 @@
 use crate::traits::{Analyzer, Solution};
 use crate::{
+    error::LinalgError,
     matrix::Matrix,
-    solver::gauss,
-    traits::{Augment, Transpose},
+    solver::{
+        cholesky::Cholesky,
+        qr,
+        refinement::{self, RefinementSettings, SolveReport},
+        svd,
+    },
+    traits::Transpose,
     vector::Vector,
 };
 use std::fmt;
 
 #[derive(Debug)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LeastSquaresApproximationSolution {
     pub weights: Vector<f64>,
 }
 
+impl LeastSquaresApproximationSolution {
+    /// The fitted polynomial at `x`, evaluated by Horner's rule.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{approximator::least_squares::LeastSquaresApproximationSolution, row, vector::Row};
+    ///
+    /// let fit = LeastSquaresApproximationSolution { weights: row![1.0, 2.0] };
+    /// assert_eq!(7.0, fit.evaluate(3.0));
+    /// ```
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.weights.iter().rev().fold(0.0, |acc, w| acc * x + w)
+    }
+}
+
 impl Solution for LeastSquaresApproximationSolution {
     fn lhs(&self) -> &'static str {
         "φ\u{0302} = "
@@ -39,38 +61,163 @@ impl fmt::Display for LeastSquaresApproximationSolution {
     }
 }
 
+/// How `LeastSquaresApproximator` solves for its weights.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LeastSquaresMethod {
+    /// Form the normal equations XᵀXw = Xᵀy and solve them by Cholesky decomposition.
+    /// Cheapest, but squares the condition number of X, so accuracy suffers once
+    /// x values grow large.
+    NormalEquations,
+    /// Solve Xw ≈ y directly by Householder QR decomposition of X.
+    Qr,
+    /// Solve Xw ≈ y by singular value decomposition of X, taking the smallest
+    /// norm weights when X is rank-deficient instead of failing.
+    Svd,
+}
+
 pub struct LeastSquaresApproximator {
-    x: Vec<Vec<f64>>,
-    y: Vec<Vec<f64>>,
+    x: Vec<f64>,
+    y: Vec<f64>,
+    method: LeastSquaresMethod,
 }
 
 impl LeastSquaresApproximator {
     pub fn new() -> LeastSquaresApproximator {
+        LeastSquaresApproximator::with_method(LeastSquaresMethod::NormalEquations)
+    }
+
+    /// # Example
+    /// ```
+    /// use launearalg::approximator::least_squares::*;
+    ///
+    /// let approximator = LeastSquaresApproximator::with_method(LeastSquaresMethod::Qr);
+    /// ```
+    pub fn with_method(method: LeastSquaresMethod) -> LeastSquaresApproximator {
         LeastSquaresApproximator {
             x: vec![],
             y: vec![],
+            method,
         }
     }
+
+    /// Solves the normal equations XᵀXw = Xᵀy for the weights by LU
+    /// decomposition, refining as `settings` allows, and reports on the
+    /// solution's accuracy. This reports on the normal equations whichever
+    /// `LeastSquaresMethod` is configured; a large condition estimate here
+    /// warns that the samples pin the weights down poorly for every method.
+    ///
+    /// Returns an error if no samples have been analyzed or XᵀX is singular.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{approximator::least_squares::LeastSquaresApproximator, solver::refinement::RefinementSettings, traits::Analyzer};
+    ///
+    /// let mut approximator = LeastSquaresApproximator::new();
+    /// for x in 0..4 {
+    ///     approximator.analyze_piecewise(vec![(x as f64, 2.0 * x as f64 + 1.0)]);
+    /// }
+    ///
+    /// let report = approximator.report_normal_equations(&RefinementSettings::default()).unwrap();
+    /// assert!((report.solution[1] - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn report_normal_equations(
+        &self,
+        settings: &RefinementSettings<f64>,
+    ) -> Result<SolveReport<f64>, LinalgError> {
+        let (xtx, xty) = self.normal_equations()?;
+        refinement::solve(&xtx, &xty, settings)
+    }
+
+    // XᵀX and Xᵀy for the Vandermonde matrix X of the samples.
+    fn normal_equations(&self) -> Result<(Matrix<f64>, Vector<f64>), LinalgError> {
+        if self.x.is_empty() {
+            return Err(LinalgError::Empty);
+        }
+        let core_x = Matrix::vandermonde(&self.x, 1);
+        let core_xt = core_x.transpose();
+        Ok((&core_xt * &core_x, &core_xt * &Vector::from(&self.y)))
+    }
 }
+
+impl Default for LeastSquaresApproximator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Analyzer for LeastSquaresApproximator {
     type Output = dyn Solution;
 
     fn analyze_piecewise(&mut self, points: Vec<(f64, f64)>) -> Option<Box<Self::Output>> {
-        self.x.push(vec![1.0, points[0].0]);
-        self.y.push(vec![points[0].1]);
+        self.x.push(points[0].0);
+        self.y.push(points[0].1);
         None
     }
 
-    fn analyze_global(&mut self) -> Option<Box<Self::Output>> {
-        let core_x = Matrix::from(self.x.clone());
-        let core_y = Matrix::from(self.y.clone());
+    fn analyze_global(&mut self) -> Result<Option<Box<Self::Output>>, LinalgError> {
+        if self.x.is_empty() {
+            return Err(LinalgError::Empty);
+        }
+        let core_x = Matrix::vandermonde(&self.x, 1);
+        let core_y = Vector::from(&self.y);
 
-        let core_xt = core_x.transpose();
-        let core_xtx = &core_xt * &core_x;
-        let core_xty = &core_xt * &core_y;
-        let core_xtxxty = core_xtx.augment(&core_xty);
+        let weights = match self.method {
+            LeastSquaresMethod::NormalEquations => {
+                let (core_xtx, core_xty) = self.normal_equations()?;
+
+                // XᵀX is symmetric positive definite unless the samples can't pin down
+                // every weight, in which case there is no meaningful fit to report.
+                Cholesky::new(&core_xtx)?.solve(&core_xty)
+            }
+            LeastSquaresMethod::Qr => qr::least_squares_qr(&core_x, &core_y)?,
+            LeastSquaresMethod::Svd => svd::least_squares_svd(&core_x, &core_y)?,
+        };
+
+        Ok(Some(Box::new(LeastSquaresApproximationSolution {
+            weights,
+        })))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fit(method: LeastSquaresMethod, points: &[(f64, f64)]) -> Result<String, LinalgError> {
+        let mut approximator = LeastSquaresApproximator::with_method(method);
+        for &point in points {
+            approximator.analyze_piecewise(vec![point]);
+        }
+        approximator
+            .analyze_global()
+            .map(|solution| solution.unwrap().to_string())
+    }
+
+    #[test]
+    fn test_methods_agree_on_well_conditioned_fit() {
+        let points = [(0.0, 1.1), (1.0, 2.9), (2.0, 5.2), (3.0, 6.8), (4.0, 9.1)];
+        let normal = fit(LeastSquaresMethod::NormalEquations, &points).unwrap();
+        assert_eq!(
+            "1.0400 + 1.9900x; global least squares approximation",
+            normal
+        );
+        assert_eq!(Ok(normal.clone()), fit(LeastSquaresMethod::Qr, &points));
+        assert_eq!(Ok(normal), fit(LeastSquaresMethod::Svd, &points));
+    }
 
-        let weights = gauss::solve(core_xtxxty);
-        Some(Box::new(LeastSquaresApproximationSolution { weights }))
+    #[test]
+    fn test_rank_deficient_fit() {
+        // Every sample at x = 0 pins down the intercept but not the slope.
+        let points = [(0.0, 1.0), (0.0, 2.0), (0.0, 3.0)];
+        assert!(fit(LeastSquaresMethod::NormalEquations, &points).is_err());
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 1 }),
+            fit(LeastSquaresMethod::Qr, &points)
+        );
+        // The smallest norm fit puts no weight on the slope.
+        assert_eq!(
+            Ok("2.0000 + 0.0000x; global least squares approximation".to_string()),
+            fit(LeastSquaresMethod::Svd, &points)
+        );
     }
 }
@@ -0,0 +1,618 @@
+use crate::{
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    operator::LinearOperator,
+    solver::iterative::RowAccess,
+    vector::Vector,
+};
+use num_traits::{Float, Num, NumAssignOps};
+
+/// A square n x n matrix whose only non-zero entries lie on its main diagonal
+/// and the diagonals directly above and below it.
+///
+/// Only the three diagonals are stored, and systems are solved in O(n) by the
+/// Thomas algorithm, rather than the O(n³) of elimination on a dense `Matrix`.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TridiagonalMatrix<T> {
+    lower: Vec<T>,
+    diagonal: Vec<T>,
+    upper: Vec<T>,
+}
+
+impl<T> TridiagonalMatrix<T>
+where
+    T: Num + Copy + NumAssignOps,
+{
+    /// Create an n x n `TridiagonalMatrix` of zeros.
+    ///
+    /// # Panics
+    /// Panics if `n` is 0.
+    pub fn new(n: usize) -> Self {
+        assert!(n > 0, "TridiagonalMatrix must have at least one row");
+        TridiagonalMatrix {
+            lower: vec![T::zero(); n - 1],
+            diagonal: vec![T::zero(); n],
+            upper: vec![T::zero(); n - 1],
+        }
+    }
+
+    /// Create a `TridiagonalMatrix` from its sub-diagonal, main diagonal and
+    /// super-diagonal.
+    ///
+    /// Returns an error if `diagonal` is empty, or `lower` and `upper` do not
+    /// both have one entry fewer than it.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{banded::TridiagonalMatrix, mat, matrix::Matrix};
+    ///
+    /// let t = TridiagonalMatrix::from_diagonals(vec![1, 2], vec![3, 4, 5], vec![6, 7]).unwrap();
+    /// assert_eq!(mat![[3, 6, 0], [1, 4, 7], [0, 2, 5]], t.to_matrix());
+    /// ```
+    pub fn from_diagonals(lower: Vec<T>, diagonal: Vec<T>, upper: Vec<T>) -> Result<Self> {
+        let n = diagonal.len();
+        if n == 0 {
+            return Err(LinalgError::Empty);
+        }
+        for band in &[&lower, &upper] {
+            if band.len() != n - 1 {
+                return Err(LinalgError::DimensionMismatch {
+                    expected: (n - 1, 1),
+                    found: (band.len(), 1),
+                });
+            }
+        }
+
+        Ok(TridiagonalMatrix {
+            lower,
+            diagonal,
+            upper,
+        })
+    }
+
+    /// Number of rows, which is also the number of columns.
+    pub fn size(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    /// The sub-diagonal, entries (i + 1, i).
+    pub fn lower(&self) -> &[T] {
+        &self.lower
+    }
+
+    /// The main diagonal, entries (i, i).
+    pub fn diagonal(&self) -> &[T] {
+        &self.diagonal
+    }
+
+    /// The super-diagonal, entries (i, i + 1).
+    pub fn upper(&self) -> &[T] {
+        &self.upper
+    }
+
+    /// The sub-diagonal, entries (i + 1, i), for writing.
+    pub fn lower_mut(&mut self) -> &mut [T] {
+        &mut self.lower
+    }
+
+    /// The main diagonal, entries (i, i), for writing.
+    pub fn diagonal_mut(&mut self) -> &mut [T] {
+        &mut self.diagonal
+    }
+
+    /// The super-diagonal, entries (i, i + 1), for writing.
+    pub fn upper_mut(&mut self) -> &mut [T] {
+        &mut self.upper
+    }
+
+    /// The entry at (i, j), zero outside the three diagonals.
+    ///
+    /// # Panics
+    /// Panics if (i, j) is outside the matrix.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        let n = self.size();
+        assert!(
+            i < n && j < n,
+            "({}, {}) is outside a {}x{} matrix",
+            i,
+            j,
+            n,
+            n
+        );
+
+        if i == j {
+            self.diagonal[i]
+        } else if i == j + 1 {
+            self.lower[j]
+        } else if j == i + 1 {
+            self.upper[i]
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Computes Ax.
+    ///
+    /// # Panics
+    /// Panics if `x` does not have as many entries as the matrix has rows.
+    pub fn mul_vector(&self, x: &Vector<T>) -> Vector<T> {
+        let n = self.size();
+        assert_eq!(n, x.len());
+
+        Vector::from(
+            (0..n)
+                .map(|i| {
+                    let mut sum = self.diagonal[i] * x[i];
+                    if i > 0 {
+                        sum += self.lower[i - 1] * x[i - 1];
+                    }
+                    if i + 1 < n {
+                        sum += self.upper[i] * x[i + 1];
+                    }
+                    sum
+                })
+                .collect::<Vec<T>>(),
+        )
+    }
+
+    /// Solves Ax = b by the Thomas algorithm in O(n).
+    ///
+    /// The Thomas algorithm is Gaussian elimination without pivoting, so it is
+    /// stable for diagonally dominant or symmetric positive definite matrices,
+    /// such as those of cubic splines. Returns `LinalgError::Singular` if a zero
+    /// pivot is met, and `LinalgError::DimensionMismatch` if `b` has the wrong
+    /// length.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{banded::TridiagonalMatrix, row, vector::Row};
+    ///
+    /// let t = TridiagonalMatrix::from_diagonals(
+    ///     vec![1.0, 1.0],
+    ///     vec![4.0, 4.0, 4.0],
+    ///     vec![1.0, 1.0],
+    /// )
+    /// .unwrap();
+    ///
+    /// assert_eq!(row![1.0, 1.0, 1.0], t.solve(&row![5.0, 6.0, 5.0]).unwrap());
+    /// ```
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>> {
+        let n = self.size();
+        if b.len() != n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (n, 1),
+                found: (b.len(), 1),
+            });
+        }
+
+        // Forward sweep: eliminate the sub-diagonal, normalising each pivot to 1.
+        let mut upper = vec![T::zero(); n];
+        let mut x: Vec<T> = b.iter().copied().collect();
+        for i in 0..n {
+            let mut pivot = self.diagonal[i];
+            if i > 0 {
+                pivot -= self.lower[i - 1] * upper[i - 1];
+                let scaled = self.lower[i - 1] * x[i - 1];
+                x[i] -= scaled;
+            }
+            if pivot.is_zero() {
+                return Err(LinalgError::Singular { pivot: i });
+            }
+
+            if i + 1 < n {
+                upper[i] = self.upper[i] / pivot;
+            }
+            x[i] /= pivot;
+        }
+
+        for i in (0..n - 1).rev() {
+            let scaled = upper[i] * x[i + 1];
+            x[i] -= scaled;
+        }
+
+        Ok(Vector::from(x))
+    }
+
+    /// The equivalent dense `Matrix`.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let n = self.size();
+        let mut m = Matrix::new(n, n);
+        for i in 0..n {
+            m[i][i] = self.diagonal[i];
+            if i + 1 < n {
+                m[i + 1][i] = self.lower[i];
+                m[i][i + 1] = self.upper[i];
+            }
+        }
+        m
+    }
+}
+
+impl<T> LinearOperator<T> for TridiagonalMatrix<T>
+where
+    T: Num + Copy + NumAssignOps,
+{
+    fn dims(&self) -> (usize, usize) {
+        (self.size(), self.size())
+    }
+
+    fn apply(&self, x: &Vector<T>) -> Vector<T> {
+        self.mul_vector(x)
+    }
+
+    // Aᵀ is tridiagonal too, with the sub- and super-diagonals exchanged.
+    fn apply_transpose(&self, x: &Vector<T>) -> Vector<T> {
+        let n = self.size();
+        assert_eq!(n, x.len());
+
+        Vector::from(
+            (0..n)
+                .map(|i| {
+                    let mut sum = self.diagonal[i] * x[i];
+                    if i > 0 {
+                        sum += self.upper[i - 1] * x[i - 1];
+                    }
+                    if i + 1 < n {
+                        sum += self.lower[i] * x[i + 1];
+                    }
+                    sum
+                })
+                .collect::<Vec<T>>(),
+        )
+    }
+}
+
+impl<T> RowAccess<T> for TridiagonalMatrix<T>
+where
+    T: Float + NumAssignOps,
+{
+    fn dims(&self) -> (usize, usize) {
+        (self.size(), self.size())
+    }
+
+    fn get(&self, i: usize, j: usize) -> T {
+        TridiagonalMatrix::get(self, i, j)
+    }
+
+    fn for_each_in_row<F>(&self, i: usize, mut f: F)
+    where
+        F: FnMut(usize, T),
+    {
+        if i > 0 {
+            f(i - 1, self.lower[i - 1]);
+        }
+        f(i, self.diagonal[i]);
+        if i + 1 < self.size() {
+            f(i + 1, self.upper[i]);
+        }
+    }
+}
+
+/// A square n x n matrix whose non-zero entries lie at most `lower_bandwidth`
+/// diagonals below, and `upper_bandwidth` diagonals above, its main diagonal.
+///
+/// Each row keeps only its band, so storage is O(n · (kl + ku)), and systems
+/// are solved in O(n · kl · ku) by elimination restricted to the band.
+#[derive(Debug, PartialEq, Clone)]
+pub struct BandedMatrix<T> {
+    size: usize,
+    lower_bandwidth: usize,
+    upper_bandwidth: usize,
+    // Row i holds entries (i, i - kl) through (i, i + ku), padded at the edges.
+    data: Vec<T>,
+}
+
+impl<T> BandedMatrix<T>
+where
+    T: Num + Copy + NumAssignOps,
+{
+    /// Create an n x n `BandedMatrix` of zeros, with `lower_bandwidth`
+    /// sub-diagonals and `upper_bandwidth` super-diagonals.
+    ///
+    /// # Panics
+    /// Panics if `n` is 0.
+    pub fn new(n: usize, lower_bandwidth: usize, upper_bandwidth: usize) -> Self {
+        assert!(n > 0, "BandedMatrix must have at least one row");
+        BandedMatrix {
+            size: n,
+            lower_bandwidth,
+            upper_bandwidth,
+            data: vec![T::zero(); n * (lower_bandwidth + upper_bandwidth + 1)],
+        }
+    }
+
+    /// Number of rows, which is also the number of columns.
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Number of diagonals below the main diagonal that may be non-zero.
+    pub fn lower_bandwidth(&self) -> usize {
+        self.lower_bandwidth
+    }
+
+    /// Number of diagonals above the main diagonal that may be non-zero.
+    pub fn upper_bandwidth(&self) -> usize {
+        self.upper_bandwidth
+    }
+
+    /// Whether (i, j) lies inside the band.
+    pub fn in_band(&self, i: usize, j: usize) -> bool {
+        i < self.size
+            && j < self.size
+            && j + self.lower_bandwidth >= i
+            && j <= i + self.upper_bandwidth
+    }
+
+    /// The entry at (i, j), zero outside the band.
+    ///
+    /// # Panics
+    /// Panics if (i, j) is outside the matrix.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        let n = self.size;
+        assert!(
+            i < n && j < n,
+            "({}, {}) is outside a {}x{} matrix",
+            i,
+            j,
+            n,
+            n
+        );
+
+        if self.in_band(i, j) {
+            self.data[self.offset(i, j)]
+        } else {
+            T::zero()
+        }
+    }
+
+    /// Set the entry at (i, j).
+    ///
+    /// # Panics
+    /// Panics if (i, j) is outside the band.
+    pub fn set(&mut self, i: usize, j: usize, value: T) {
+        assert!(self.in_band(i, j), "({}, {}) is outside the band", i, j);
+        let offset = self.offset(i, j);
+        self.data[offset] = value;
+    }
+
+    /// Computes Ax.
+    ///
+    /// # Panics
+    /// Panics if `x` does not have as many entries as the matrix has rows.
+    pub fn mul_vector(&self, x: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.size, x.len());
+
+        Vector::from(
+            (0..self.size)
+                .map(|i| {
+                    self.band_columns(i)
+                        .fold(T::zero(), |acc, j| acc + self.get(i, j) * x[j])
+                })
+                .collect::<Vec<T>>(),
+        )
+    }
+
+    /// Solves Ax = b by Gaussian elimination confined to the band.
+    ///
+    /// No pivoting is done, which keeps the fill-in inside the band, so it is
+    /// stable for diagonally dominant or symmetric positive definite matrices.
+    /// Returns `LinalgError::Singular` if a zero pivot is met, and
+    /// `LinalgError::DimensionMismatch` if `b` has the wrong length.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{banded::BandedMatrix, row, vector::Row};
+    ///
+    /// // Upper bidiagonal
+    /// let mut a = BandedMatrix::new(3, 0, 1);
+    /// for i in 0..3 {
+    ///     a.set(i, i, 2.0);
+    /// }
+    /// a.set(0, 1, 1.0);
+    /// a.set(1, 2, 1.0);
+    ///
+    /// assert_eq!(row![1.0, 1.0, 1.0], a.solve(&row![3.0, 3.0, 2.0]).unwrap());
+    /// ```
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>> {
+        let n = self.size;
+        if b.len() != n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (n, 1),
+                found: (b.len(), 1),
+            });
+        }
+
+        let mut lu = self.clone();
+        let mut x: Vec<T> = b.iter().copied().collect();
+
+        for k in 0..n {
+            let pivot = lu.get(k, k);
+            if pivot.is_zero() {
+                return Err(LinalgError::Singular { pivot: k });
+            }
+
+            let last_row = (k + self.lower_bandwidth).min(n - 1);
+            let last_col = (k + self.upper_bandwidth).min(n - 1);
+            for i in k + 1..=last_row {
+                let multiplier = lu.get(i, k) / pivot;
+                for j in k + 1..=last_col {
+                    let offset = lu.offset(i, j);
+                    let scaled = multiplier * lu.get(k, j);
+                    lu.data[offset] -= scaled;
+                }
+                let scaled = multiplier * x[k];
+                x[i] -= scaled;
+            }
+        }
+
+        for i in (0..n).rev() {
+            for j in i + 1..=(i + self.upper_bandwidth).min(n - 1) {
+                let scaled = lu.get(i, j) * x[j];
+                x[i] -= scaled;
+            }
+            x[i] /= lu.get(i, i);
+        }
+
+        Ok(Vector::from(x))
+    }
+
+    /// The equivalent dense `Matrix`.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let mut m = Matrix::new(self.size, self.size);
+        for i in 0..self.size {
+            for j in self.band_columns(i) {
+                m[i][j] = self.get(i, j);
+            }
+        }
+        m
+    }
+
+    fn band_columns(&self, i: usize) -> std::ops::RangeInclusive<usize> {
+        i.saturating_sub(self.lower_bandwidth)..=(i + self.upper_bandwidth).min(self.size - 1)
+    }
+
+    fn offset(&self, i: usize, j: usize) -> usize {
+        i * (self.lower_bandwidth + self.upper_bandwidth + 1) + j + self.lower_bandwidth - i
+    }
+}
+
+impl<T> LinearOperator<T> for BandedMatrix<T>
+where
+    T: Num + Copy + NumAssignOps,
+{
+    fn dims(&self) -> (usize, usize) {
+        (self.size, self.size)
+    }
+
+    fn apply(&self, x: &Vector<T>) -> Vector<T> {
+        self.mul_vector(x)
+    }
+
+    // Scatters each row's band, weighted by x, without forming Aᵀ.
+    fn apply_transpose(&self, x: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.size, x.len());
+
+        let mut product = vec![T::zero(); self.size];
+        for (i, xi) in x.iter().enumerate() {
+            for j in self.band_columns(i) {
+                product[j] += self.get(i, j) * *xi;
+            }
+        }
+        Vector::from(product)
+    }
+}
+
+impl<T> RowAccess<T> for BandedMatrix<T>
+where
+    T: Float + NumAssignOps,
+{
+    fn dims(&self) -> (usize, usize) {
+        (self.size, self.size)
+    }
+
+    fn get(&self, i: usize, j: usize) -> T {
+        BandedMatrix::get(self, i, j)
+    }
+
+    fn for_each_in_row<F>(&self, i: usize, mut f: F)
+    where
+        F: FnMut(usize, T),
+    {
+        for j in self.band_columns(i) {
+            f(j, self.get(i, j));
+        }
+    }
+}
+
+impl<T> From<&TridiagonalMatrix<T>> for BandedMatrix<T>
+where
+    T: Num + Copy + NumAssignOps,
+{
+    fn from(t: &TridiagonalMatrix<T>) -> Self {
+        let n = t.size();
+        let mut banded = BandedMatrix::new(n, 1, 1);
+        for i in 0..n {
+            banded.set(i, i, t.diagonal[i]);
+            if i + 1 < n {
+                banded.set(i + 1, i, t.lower[i]);
+                banded.set(i, i + 1, t.upper[i]);
+            }
+        }
+        banded
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mat, row,
+        solver::lu::LuFactorization,
+        test_util::{assert_vector_close, spline_system},
+        vector::Row,
+    };
+
+    #[test]
+    fn test_thomas_matches_lu() {
+        let t = spline_system(50);
+        let b = Vector::from((0..50).map(|i| (i as f64).cos()).collect::<Vec<f64>>());
+
+        let expected = LuFactorization::new(&t.to_matrix()).unwrap().solve(&b);
+        let x = t.solve(&b).unwrap();
+
+        assert_vector_close(&expected, &x);
+        assert_vector_close(&b, &t.mul_vector(&x));
+        assert_vector_close(&x, &BandedMatrix::from(&t).solve(&b).unwrap());
+    }
+
+    #[test]
+    fn test_banded_matches_lu() {
+        let n = 12;
+        let mut a = BandedMatrix::new(n, 2, 1);
+        for i in 0..n {
+            for j in 0..n {
+                if a.in_band(i, j) {
+                    a.set(
+                        i,
+                        j,
+                        if i == j {
+                            10.0
+                        } else {
+                            (i + 2 * j) as f64 / 10.0
+                        },
+                    );
+                }
+            }
+        }
+        let b = Vector::from((0..n).map(|i| i as f64).collect::<Vec<f64>>());
+
+        let dense = a.to_matrix();
+        assert_eq!(0.0, dense[0][3]);
+        assert_eq!(0.0, dense[5][2]);
+        assert_eq!(dense[5][3], a.get(5, 3));
+
+        let expected = LuFactorization::new(&dense).unwrap().solve(&b);
+        assert_vector_close(&expected, &a.solve(&b).unwrap());
+        assert_vector_close(&b, &a.mul_vector(&expected));
+    }
+
+    #[test]
+    fn test_rejects_bad_input() {
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 1),
+                found: (1, 1)
+            }),
+            TridiagonalMatrix::from_diagonals(vec![1.0], vec![1.0, 1.0, 1.0], vec![1.0, 1.0])
+        );
+
+        let singular =
+            TridiagonalMatrix::from_diagonals(vec![1.0], vec![1.0, 1.0], vec![1.0]).unwrap();
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 1 }),
+            singular.solve(&row![1.0, 2.0])
+        );
+        assert_eq!(mat![[1.0, 1.0], [1.0, 1.0]], singular.to_matrix());
+    }
+}
@@ -0,0 +1,72 @@
+use std::fmt;
+
+/// Reasons an operation on a `Matrix` or `Vector`, or a solver, could not
+/// produce a result.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum LinalgError {
+    /// An operand had no entries, or too few to operate on.
+    Empty,
+    /// An operand's shape, given as (rows, cols), is incompatible with the other
+    /// operand. Vectors are reported as (len, 1).
+    DimensionMismatch {
+        expected: (usize, usize),
+        found: (usize, usize),
+    },
+    /// The operation requires a square matrix.
+    NotSquare { rows: usize, cols: usize },
+    /// The operation requires a symmetric matrix.
+    NotSymmetric,
+    /// The operation requires a triangular matrix, but the entry at (row, col),
+    /// on the wrong side of the diagonal, is not zero.
+    NotTriangular { row: usize, col: usize },
+    /// A non-positive pivot was met at the given index, so the matrix is not
+    /// positive definite. For normal equations this means XᵀX is rank-deficient.
+    NotPositiveDefinite { pivot: usize },
+    /// A zero pivot was met at the given index, so the matrix is singular. Floating
+    /// point decompositions also report pivots lost in rounding error this way.
+    Singular { pivot: usize },
+    /// An entry at (row, col) was NaN or infinite.
+    NonFinite { row: usize, col: usize },
+    /// An iterative method did not converge within the given number of iterations.
+    NoConvergence { iterations: usize },
+    /// A scalar argument, named here, was NaN or outside the range it may take.
+    InvalidParameter { name: &'static str },
+}
+
+pub type Result<T> = std::result::Result<T, LinalgError>;
+
+impl std::error::Error for LinalgError {}
+impl fmt::Display for LinalgError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::result::Result<(), fmt::Error> {
+        match *self {
+            LinalgError::Empty => write!(f, "Not enough entries to operate on"),
+            LinalgError::DimensionMismatch { expected, found } => write!(
+                f,
+                "Expected a {}x{} operand, found {}x{}",
+                expected.0, expected.1, found.0, found.1
+            ),
+            LinalgError::NotSquare { rows, cols } => {
+                write!(f, "Matrix is not square ({}x{})", rows, cols)
+            }
+            LinalgError::NotSymmetric => write!(f, "Matrix is not symmetric"),
+            LinalgError::NotTriangular { row, col } => {
+                write!(f, "Matrix is not triangular (entry ({}, {}))", row, col)
+            }
+            LinalgError::NotPositiveDefinite { pivot } => {
+                write!(f, "Matrix is not positive definite (pivot {})", pivot)
+            }
+            LinalgError::Singular { pivot } => {
+                write!(f, "Matrix is singular (pivot {})", pivot)
+            }
+            LinalgError::NonFinite { row, col } => {
+                write!(f, "Entry ({}, {}) is not finite", row, col)
+            }
+            LinalgError::NoConvergence { iterations } => {
+                write!(f, "Did not converge within {} iterations", iterations)
+            }
+            LinalgError::InvalidParameter { name } => {
+                write!(f, "Parameter `{}` is out of range", name)
+            }
+        }
+    }
+}
@@ -1,18 +1,71 @@
 use crate::{
+    banded::TridiagonalMatrix,
+    error::LinalgError,
+    solver::refinement::{self, RefinementSettings, SolveReport},
+    traits::{Analyzer, Solution},
     vector::Vector,
-    matrix::Matrix,
-    solver::gauss,
-    traits::{Analyzer, Augment, Solution},
 };
 use std::fmt;
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "SplineData")
+)]
 pub struct CubicSplineInterpolationSolution {
     b: Vector<f64>,
     c: Vector<f64>,
     d: Vector<f64>,
     x: Vector<f64>,
-    y: Vector<f64>
+    y: Vector<f64>,
+}
+
+impl CubicSplineInterpolationSolution {
+    /// The spline at `x`, using the piece S_i whose interval [x_i, x_i+1]
+    /// contains it. Returns `None` if `x` is outside every piece.
+    pub fn evaluate(&self, x: f64) -> Option<f64> {
+        let i = (0..self.b.len()).find(|&i| self.x[i] <= x && x <= self.x[i + 1])?;
+        let dx = x - self.x[i];
+        Some(self.y[i] + dx * (self.b[i] + dx * (self.c[i] + dx * self.d[i])))
+    }
+}
+
+// A spline as it was serialized, before its coefficients have been checked to
+// describe the same number of pieces.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct SplineData {
+    b: Vector<f64>,
+    c: Vector<f64>,
+    d: Vector<f64>,
+    x: Vector<f64>,
+    y: Vector<f64>,
+}
+
+#[cfg(feature = "serde")]
+impl std::convert::TryFrom<SplineData> for CubicSplineInterpolationSolution {
+    type Error = LinalgError;
+    fn try_from(s: SplineData) -> Result<Self, LinalgError> {
+        // b and d have an entry per piece; c, x and y one per knot.
+        let pieces = s.b.len();
+        let expected = [pieces, pieces + 1, pieces + 1, pieces + 1];
+        for (v, &len) in [&s.d, &s.c, &s.x, &s.y].iter().zip(expected.iter()) {
+            if v.len() != len {
+                return Err(LinalgError::DimensionMismatch {
+                    expected: (len, 1),
+                    found: (v.len(), 1),
+                });
+            }
+        }
+        Ok(CubicSplineInterpolationSolution {
+            b: s.b,
+            c: s.c,
+            d: s.d,
+            x: s.x,
+            y: s.y,
+        })
+    }
 }
 impl Solution for CubicSplineInterpolationSolution {
     fn lhs(&self) -> &'static str {
@@ -22,26 +75,28 @@ impl Solution for CubicSplineInterpolationSolution {
 
 impl std::fmt::Display for CubicSplineInterpolationSolution {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> Result<(), fmt::Error> {
-        self.b.iter().enumerate().map(|(i, _x)| {
-        writeln!(f, "{:9} = {:3} + {:20} + {:20} + {:20} on [{:5}, {:5}]; cubic spline", 
-            format!("S_{}(x)", i), 
-            format!("{}", self.y[i]), 
-            format!("{:8.4}(x - {})", self.b[i], self.x[i]),
-            format!("{:8.4}(x - {})\u{00B2}", self.c[i], self.x[i]),
-            format!("{:8.4}(x - {})\u{00B3}", self.d[i], self.x[i]),
-            self.x[i],
-            self.x[i+1]
-        )?;
-        
-        Ok(())
-        }).collect()
+        self.b.iter().enumerate().try_for_each(|(i, _x)| {
+            writeln!(
+                f,
+                "{:9} = {:3} + {:20} + {:20} + {:20} on [{:5}, {:5}]; cubic spline",
+                format!("S_{}(x)", i),
+                format!("{}", self.y[i]),
+                format!("{:8.4}(x - {})", self.b[i], self.x[i]),
+                format!("{:8.4}(x - {})\u{00B2}", self.c[i], self.x[i]),
+                format!("{:8.4}(x - {})\u{00B3}", self.d[i], self.x[i]),
+                self.x[i],
+                self.x[i + 1]
+            )?;
+
+            Ok(())
+        })
     }
 }
 pub struct CubicSplineInterpolator {
     delta_x: Vec<f64>,
     delta_y: Vec<f64>,
     x_values: Vec<f64>,
-    y_values: Vec<f64>
+    y_values: Vec<f64>,
 }
 
 impl CubicSplineInterpolator {
@@ -50,8 +105,64 @@ impl CubicSplineInterpolator {
             delta_x: Vec::new(),
             delta_y: Vec::new(),
             x_values: Vec::new(),
-            y_values: Vec::new()
+            y_values: Vec::new(),
+        }
+    }
+
+    /// Solves the tridiagonal system for the spline's c coefficients by the
+    /// Thomas algorithm, as `analyze_global` does, refining as `settings`
+    /// allows, and reports on the solution's accuracy.
+    ///
+    /// Returns an error if fewer than two intervals have been analyzed.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{interpolater::cubic_spline::CubicSplineInterpolator, solver::refinement::RefinementSettings, traits::Analyzer};
+    ///
+    /// let mut spline = CubicSplineInterpolator::new();
+    /// spline.analyze_piecewise(vec![(0.0, 0.0), (1.0, 1.0)]);
+    /// spline.analyze_piecewise(vec![(1.0, 1.0), (2.0, 0.0)]);
+    /// spline.analyze_piecewise(vec![(2.0, 0.0), (3.0, 1.0)]);
+    ///
+    /// let report = spline.report(&RefinementSettings::default()).unwrap();
+    /// assert!(report.backward_error < 1e-15);
+    /// ```
+    pub fn report(
+        &self,
+        settings: &RefinementSettings<f64>,
+    ) -> Result<SolveReport<f64>, LinalgError> {
+        let (a, b) = self.system()?;
+        refinement::solve_tridiagonal(&a, &b, settings)
+    }
+
+    // The natural spline's equations for its c coefficients, one per knot.
+    fn system(&self) -> Result<(TridiagonalMatrix<f64>, Vector<f64>), LinalgError> {
+        let size = self.delta_x.len();
+        if size < 2 {
+            return Err(LinalgError::Empty);
         }
+
+        let mut a = TridiagonalMatrix::<f64>::new(size);
+        let mut b = vec![0.0; size];
+
+        a.diagonal_mut()[0] = 1.0;
+        a.diagonal_mut()[size - 1] = 1.0;
+
+        for (i, rhs) in b.iter_mut().enumerate().take(size - 1).skip(1) {
+            a.lower_mut()[i - 1] = self.delta_x[i - 1];
+            a.upper_mut()[i] = self.delta_x[i];
+            a.diagonal_mut()[i] = 2.0 * (self.delta_x[i - 1] + self.delta_x[i]);
+            *rhs =
+                3.0 * self.delta_y[i] / self.delta_x[i] - self.delta_y[i - 1] / self.delta_x[i - 1];
+        }
+
+        Ok((a, Vector::from(b)))
+    }
+}
+
+impl Default for CubicSplineInterpolator {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -69,40 +180,81 @@ impl Analyzer for CubicSplineInterpolator {
         None
     }
 
-    fn analyze_global(&mut self) -> Option<Box<Self::Output>> {
+    fn analyze_global(&mut self) -> Result<Option<Box<Self::Output>>, LinalgError> {
         let size = self.delta_x.len();
-
-        let mut a = Matrix::<f64>::new(size, size);
-        let mut b = Matrix::<f64>::new(size, 1);
-
-        a[0][0] = 1.0;
-        a[size - 1][size - 1] = 1.0;
-
-        for i in 1..(size - 1) {
-            a[i][i - 1] = self.delta_x[i - 1];
-            a[i][i + 1] = self.delta_x[i];
-            a[i][i] = 2.0 * (self.delta_x[i - 1] + self.delta_x[i]);
-            b[i][0] =
-                3.0 * self.delta_y[i] / self.delta_x[i] - self.delta_y[i - 1] / self.delta_x[i - 1];
-        }
-
-        let ab = a.augment(&b);
-        let c_i = gauss::solve(ab);
+        let (a, b) = self.system()?;
+        let c_i = a.solve(&b)?;
         let mut b_i = vec![0.0; size - 1];
         let mut d_i = vec![0.0; size - 1];
         for i in 0..(size - 1) {
             b_i[i] = (self.delta_y[i] / self.delta_x[i])
                 - (self.delta_x[i] / 3.0) * (2.0 * c_i[i] + c_i[i + 1]);
             d_i[i] = (c_i[i + 1] - c_i[i]) / (3.0 * self.delta_x[i]);
-            
         }
 
-        Some(Box::new(CubicSplineInterpolationSolution {
+        Ok(Some(Box::new(CubicSplineInterpolationSolution {
             b: Vector::from(b_i),
-            c: Vector::from(c_i),
+            c: c_i,
             d: Vector::from(d_i),
             x: Vector::from(self.x_values.clone()),
             y: Vector::from(self.y_values.clone()),
-        }))
+        })))
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The spline through (0, 1), (1, 3), (2, 5): the line 2x + 1.
+    fn line() -> CubicSplineInterpolationSolution {
+        CubicSplineInterpolationSolution {
+            b: Vector::from(vec![2.0, 2.0]),
+            c: Vector::from(vec![0.0, 0.0, 0.0]),
+            d: Vector::from(vec![0.0, 0.0]),
+            x: Vector::from(vec![0.0, 1.0, 2.0]),
+            y: Vector::from(vec![1.0, 3.0, 5.0]),
+        }
+    }
+
+    #[test]
+    fn test_evaluate() {
+        let spline = line();
+        assert_eq!(Some(1.0), spline.evaluate(0.0));
+        assert_eq!(Some(4.0), spline.evaluate(1.5));
+        assert_eq!(Some(5.0), spline.evaluate(2.0));
+        assert_eq!(None, spline.evaluate(2.5));
+    }
+
+    #[test]
+    fn test_report_agrees_with_analyze_global() {
+        let mut spline = CubicSplineInterpolator::new();
+        let points = [(0.0, 1.0), (0.5, 2.0), (1.5, -1.0), (3.0, 0.5), (3.5, 4.0)];
+        for pair in points.windows(2) {
+            spline.analyze_piecewise(pair.to_vec());
+        }
+        let report = spline.report(&RefinementSettings::default()).unwrap();
+        let (a, b) = spline.system().unwrap();
+        let c = a.solve(&b).unwrap();
+
+        for (x, y) in report.solution.iter().zip(c.iter()) {
+            assert!((x - y).abs() < 1e-12);
+        }
+        assert!(report.backward_error < 1e-15);
+        assert_eq!(
+            Err(LinalgError::Empty),
+            CubicSplineInterpolator::new().report(&RefinementSettings::default())
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let json = serde_json::to_string(&line()).unwrap();
+        let spline: CubicSplineInterpolationSolution = serde_json::from_str(&json).unwrap();
+        assert_eq!(line(), spline);
+
+        let short_c = json.replace("\"c\":[0.0,0.0,0.0]", "\"c\":[0.0,0.0]");
+        assert!(serde_json::from_str::<CubicSplineInterpolationSolution>(&short_c).is_err());
+    }
+}
@@ -1,11 +1,22 @@
-use crate::traits::{Analyzer, Interpolate, Solution};
+use crate::{
+    error::LinalgError,
+    traits::{Analyzer, Interpolate, Solution},
+};
 use std::fmt;
 
 #[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LinearPiecewiseInterpolationSolution {
     c0: f64,
     c1: f64,
 }
+
+impl LinearPiecewiseInterpolationSolution {
+    /// The interpolating line, c₀ + c₁x, at `x`.
+    pub fn evaluate(&self, x: f64) -> f64 {
+        self.c0 + self.c1 * x
+    }
+}
 impl Solution for LinearPiecewiseInterpolationSolution {
     fn lhs(&self) -> &'static str {
         "y_"
@@ -24,6 +35,12 @@ impl LinearPiecewiseInterpolater {
     }
 }
 
+impl Default for LinearPiecewiseInterpolater {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Interpolate for LinearPiecewiseInterpolater {
     type Output = LinearPiecewiseInterpolationSolution;
     fn interpolate(points: Vec<(f64, f64)>) -> Option<Self::Output> {
@@ -55,8 +72,8 @@ impl Analyzer for LinearPiecewiseInterpolater {
         }
     }
 
-    fn analyze_global(&mut self) -> Option<Box<Self::Output>> {
-        None
+    fn analyze_global(&mut self) -> Result<Option<Box<Self::Output>>, LinalgError> {
+        Ok(None)
     }
 }
 
@@ -75,4 +92,13 @@ mod tests {
             interp_res.unwrap()
         );
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let line = LinearPiecewiseInterpolationSolution { c0: 0.0, c1: 0.5 };
+        let json = serde_json::to_string(&line).unwrap();
+        let reloaded: LinearPiecewiseInterpolationSolution = serde_json::from_str(&json).unwrap();
+        assert_eq!(3.0, reloaded.evaluate(6.0));
+    }
 }
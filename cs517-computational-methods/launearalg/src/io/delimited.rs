@@ -0,0 +1,187 @@
+//! Delimited text tables, one row of the matrix per line, such as CSV (`','`)
+//! or TSV (`'\t'`), optionally headed by a line of column names.
+//!
+//! Fields are trimmed of surrounding whitespace, and header names of
+//! surrounding double quotes. Blank lines are skipped.
+use super::{parse, IoError, Result};
+use crate::matrix::Matrix;
+use num_traits::Num;
+use std::{
+    fmt::Display,
+    io::{BufRead, Write},
+    str::FromStr,
+};
+
+/// A `Matrix` read from a delimited file, with its column names if the file
+/// had a header.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Table<T> {
+    pub header: Option<Vec<String>>,
+    pub matrix: Matrix<T>,
+}
+
+/// Read a table of fields separated by `delimiter`, taking the first line as
+/// column names if `has_header` is set.
+///
+/// # Example
+/// ```
+/// use launearalg::{io::delimited, mat, matrix::Matrix};
+///
+/// let file = "\"x\",\"y\"\n1.0, 2.5\n2.0, 4.0\n";
+/// let table = delimited::read::<f64, _>(file.as_bytes(), ',', true).unwrap();
+///
+/// assert_eq!(Some(vec!["x".to_string(), "y".to_string()]), table.header);
+/// assert_eq!(mat![[1.0, 2.5], [2.0, 4.0]], table.matrix);
+/// ```
+pub fn read<T, R>(reader: R, delimiter: char, has_header: bool) -> Result<Table<T>>
+where
+    T: Num + Copy + FromStr,
+    R: BufRead,
+{
+    let mut lines = reader
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| match line {
+            Ok(line) if line.trim().is_empty() => None,
+            line => Some(line.map(|line| (i + 1, line))),
+        });
+
+    let header = match (has_header, lines.next()) {
+        (true, Some(line)) => Some(
+            line?
+                .1
+                .split(delimiter)
+                .map(|name| name.trim().trim_matches('"').to_string())
+                .collect::<Vec<_>>(),
+        ),
+        (true, None) => return Err(IoError::Empty),
+        (false, first) => {
+            // No header, so the first line is data; put it back.
+            return read_rows(first.into_iter().chain(lines), delimiter, None);
+        }
+    };
+    read_rows(lines, delimiter, header)
+}
+
+/// Write `matrix` with fields separated by `delimiter`, preceded by a line of
+/// column names if `header` is given.
+///
+/// # Example
+/// ```
+/// use launearalg::{io::delimited, mat, matrix::Matrix};
+///
+/// let mut file = Vec::new();
+/// delimited::write(&mut file, &mat![[1, 2], [3, 4]], Some(&["a", "b"]), '\t').unwrap();
+///
+/// assert_eq!("a\tb\n1\t2\n3\t4\n", String::from_utf8(file).unwrap());
+/// ```
+pub fn write<T, W>(
+    mut writer: W,
+    matrix: &Matrix<T>,
+    header: Option<&[&str]>,
+    delimiter: char,
+) -> Result<()>
+where
+    T: Num + Copy + Display,
+    W: Write,
+{
+    let delimiter = delimiter.to_string();
+    if let Some(names) = header {
+        if names.len() != matrix.cols {
+            return Err(IoError::Ragged {
+                line: 1,
+                expected: matrix.cols,
+                found: names.len(),
+            });
+        }
+        writeln!(writer, "{}", names.join(&delimiter))?;
+    }
+    for row in matrix.rows() {
+        let fields = row.iter().map(|x| x.to_string()).collect::<Vec<_>>();
+        writeln!(writer, "{}", fields.join(&delimiter))?;
+    }
+    Ok(())
+}
+
+fn read_rows<T, I>(lines: I, delimiter: char, header: Option<Vec<String>>) -> Result<Table<T>>
+where
+    T: Num + Copy + FromStr,
+    I: Iterator<Item = std::io::Result<(usize, String)>>,
+{
+    let mut rows: Vec<Vec<T>> = Vec::new();
+    for line in lines {
+        let (line, text) = line?;
+        let row = text
+            .split(delimiter)
+            .map(|field| parse(field.trim(), line))
+            .collect::<Result<Vec<T>>>()?;
+
+        let expected = header.as_ref().map_or_else(
+            || rows.first().map_or(row.len(), |first| first.len()),
+            |names| names.len(),
+        );
+        if row.len() != expected {
+            return Err(IoError::Ragged {
+                line,
+                expected,
+                found: row.len(),
+            });
+        }
+        rows.push(row);
+    }
+
+    if rows.is_empty() {
+        return Err(IoError::Empty);
+    }
+    Ok(Table {
+        header,
+        matrix: Matrix::from(rows),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mat;
+
+    #[test]
+    fn test_round_trip() {
+        let a = mat![[1.5, -2.0, 0.1], [3.0, 1e-10, 4.0]];
+        for &delimiter in [',', '\t'].iter() {
+            let mut file = Vec::new();
+            write(&mut file, &a, Some(&["a", "b", "c"]), delimiter).unwrap();
+
+            let table = read::<f64, _>(&file[..], delimiter, true).unwrap();
+            assert_eq!(a, table.matrix);
+            assert_eq!(3, table.header.unwrap().len());
+        }
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let read = |file: &str, header| read::<i32, _>(file.as_bytes(), ',', header).unwrap_err();
+
+        assert!(matches!(read("", false), IoError::Empty));
+        assert!(matches!(read("a,b\n", true), IoError::Empty));
+        assert!(matches!(
+            read("1,2\n\n3\n", false),
+            IoError::Ragged {
+                line: 3,
+                expected: 2,
+                found: 1
+            }
+        ));
+        assert!(matches!(
+            read("a,b,c\n1,2\n", true),
+            IoError::Ragged {
+                line: 2,
+                expected: 3,
+                found: 2
+            }
+        ));
+        assert!(matches!(
+            read("1,2\n3,4.5\n", false),
+            IoError::Parse { line: 2, .. }
+        ));
+    }
+}
@@ -0,0 +1,418 @@
+//! The [Matrix Market](https://math.nist.gov/MatrixMarket/formats.html) exchange
+//! format, as used by the NIST collection of reference systems.
+//!
+//! Both the `coordinate` (sparse) and `array` (dense, column-major) formats are
+//! read, with `real` or `integer` fields and `general`, `symmetric` or
+//! `skew-symmetric` symmetry. Symmetric files store one triangle, which is
+//! mirrored on reading.
+use super::{parse, IoError, Result};
+use crate::{matrix::Matrix, sparse::CooMatrix};
+use num_traits::{Num, NumAssignOps};
+use std::{
+    fmt::Display,
+    io::{BufRead, Write},
+    str::FromStr,
+};
+
+/// Entry types which have a Matrix Market field, the word naming them in the
+/// banner.
+pub trait MatrixMarketField {
+    const FIELD: &'static str;
+}
+
+macro_rules! matrix_market_field {
+    ($field:expr => $($t:ty),*) => {
+        $(
+            impl MatrixMarketField for $t {
+                const FIELD: &'static str = $field;
+            }
+        )*
+    };
+}
+
+matrix_market_field!("integer" => i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+matrix_market_field!("real" => f32, f64);
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Format {
+    Coordinate,
+    Array,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Symmetry {
+    General,
+    Symmetric,
+    SkewSymmetric,
+}
+
+/// Read a Matrix Market file into a dense `Matrix`.
+///
+/// Entries a coordinate file leaves out are zero. Returns
+/// `IoError::TooLarge` if the `Matrix` the header declares can't be
+/// allocated, as for a large sparse system; use
+/// [`read_coo`](fn.read_coo.html) for those.
+///
+/// # Example
+/// ```
+/// use launearalg::{io::matrix_market, mat, matrix::Matrix};
+///
+/// let file = "%%MatrixMarket matrix coordinate real symmetric
+/// % a comment
+/// 3 3 4
+/// 1 1 4.0
+/// 2 1 -1.0
+/// 2 2 4.0
+/// 3 3 2.5
+/// ";
+/// let a: Matrix<f64> = matrix_market::read(file.as_bytes()).unwrap();
+/// assert_eq!(mat![[4.0, -1.0, 0.0], [-1.0, 4.0, 0.0], [0.0, 0.0, 2.5]], a);
+/// ```
+pub fn read<T, R>(reader: R) -> Result<Matrix<T>>
+where
+    T: Num + Copy + NumAssignOps + FromStr,
+    R: BufRead,
+{
+    let coo = read_coo(reader)?;
+    let (rows, cols) = (coo.rows, coo.cols);
+    let mut matrix = Matrix::try_zeroed(rows, cols).ok_or(IoError::TooLarge { rows, cols })?;
+    for &(i, j, value) in coo.entries() {
+        matrix[i][j] += value;
+    }
+    Ok(matrix)
+}
+
+/// Read a Matrix Market file into a `CooMatrix`. Entries which are exactly
+/// zero are dropped, so an array file gives only its nonzero entries.
+pub fn read_coo<T, R>(reader: R) -> Result<CooMatrix<T>>
+where
+    T: Num + Copy + NumAssignOps + FromStr,
+    R: BufRead,
+{
+    let mut lines = reader.lines().enumerate();
+    let (format, symmetry) = match lines.next() {
+        Some((_, banner)) => parse_banner(&banner?)?,
+        None => return Err(IoError::Empty),
+    };
+
+    // Everything after the banner, less comments and blank lines.
+    let mut lines = lines.filter_map(|(i, line)| match line {
+        Ok(line) if line.trim().is_empty() || line.starts_with('%') => None,
+        line => Some(line.map(|line| (i + 1, line))),
+    });
+
+    let (size_line, size) = lines.next().ok_or(IoError::Empty)??;
+    let size = size
+        .split_whitespace()
+        .map(|token| parse::<usize>(token, size_line))
+        .collect::<Result<Vec<_>>>()?;
+    let (rows, cols, stored) = match (format, &size[..]) {
+        (Format::Coordinate, &[rows, cols, nnz]) => (rows, cols, nnz),
+        (Format::Array, &[rows, cols]) => match array_len(rows, cols, symmetry) {
+            Some(len) => (rows, cols, len),
+            None => {
+                return Err(IoError::Malformed {
+                    line: size_line,
+                    reason: "size line declares more entries than can be counted",
+                })
+            }
+        },
+        _ => {
+            return Err(IoError::Malformed {
+                line: size_line,
+                reason: "size line has the wrong number of fields",
+            })
+        }
+    };
+    if rows == 0 || cols == 0 {
+        return Err(IoError::Empty);
+    }
+    if symmetry != Symmetry::General && rows != cols {
+        return Err(IoError::Malformed {
+            line: size_line,
+            reason: "a symmetric matrix must be square",
+        });
+    }
+
+    // Positions of the entries of an array file, down each column of the
+    // stored triangle in turn.
+    let mut positions = (0..cols).flat_map(|j| {
+        let first = match symmetry {
+            Symmetry::General => 0,
+            Symmetry::Symmetric => j,
+            Symmetry::SkewSymmetric => j + 1,
+        };
+        (first..rows).map(move |i| (i, j))
+    });
+
+    let mut coo = CooMatrix::new(rows, cols);
+    let mut found = 0;
+    for line in lines {
+        let (line, text) = line?;
+        let tokens = text.split_whitespace().collect::<Vec<_>>();
+        let (i, j, value) = match (format, &tokens[..]) {
+            (Format::Coordinate, &[i, j, value]) => {
+                let (i, j) = (parse::<usize>(i, line)?, parse::<usize>(j, line)?);
+                if i == 0 || j == 0 || i > rows || j > cols {
+                    return Err(IoError::OutOfBounds {
+                        line,
+                        row: i,
+                        col: j,
+                    });
+                }
+                (i - 1, j - 1, parse::<T>(value, line)?)
+            }
+            (Format::Array, &[value]) => match positions.next() {
+                Some((i, j)) => (i, j, parse::<T>(value, line)?),
+                None => {
+                    return Err(IoError::EntryCount {
+                        expected: stored,
+                        found: found + 1,
+                    })
+                }
+            },
+            _ => {
+                return Err(IoError::Malformed {
+                    line,
+                    reason: "entry has the wrong number of fields",
+                })
+            }
+        };
+        found += 1;
+
+        if value == T::zero() {
+            continue;
+        }
+        coo.push(i, j, value);
+        if i != j {
+            match symmetry {
+                Symmetry::General => {}
+                Symmetry::Symmetric => coo.push(j, i, value),
+                Symmetry::SkewSymmetric => coo.push(j, i, T::zero() - value),
+            }
+        }
+    }
+
+    if found != stored {
+        return Err(IoError::EntryCount {
+            expected: stored,
+            found,
+        });
+    }
+    Ok(coo)
+}
+
+/// Write `matrix` in the dense array format.
+///
+/// # Example
+/// ```
+/// use launearalg::{io::matrix_market, mat, matrix::Matrix};
+///
+/// let a = mat![[1, 2], [3, 4]];
+/// let mut file = Vec::new();
+/// matrix_market::write(&mut file, &a).unwrap();
+///
+/// assert_eq!(
+///     "%%MatrixMarket matrix array integer general\n2 2\n1\n3\n2\n4\n",
+///     String::from_utf8(file).unwrap()
+/// );
+/// ```
+pub fn write<T, W>(mut writer: W, matrix: &Matrix<T>) -> Result<()>
+where
+    T: Num + Copy + Display + MatrixMarketField,
+    W: Write,
+{
+    writeln!(writer, "%%MatrixMarket matrix array {} general", T::FIELD)?;
+    writeln!(writer, "{} {}", matrix.rows, matrix.cols)?;
+    for col in matrix.cols() {
+        for entry in col {
+            writeln!(writer, "{}", entry)?;
+        }
+    }
+    Ok(())
+}
+
+/// Write `matrix` in the sparse coordinate format, one line per triplet.
+pub fn write_coo<T, W>(mut writer: W, matrix: &CooMatrix<T>) -> Result<()>
+where
+    T: Num + Copy + NumAssignOps + Display + MatrixMarketField,
+    W: Write,
+{
+    writeln!(
+        writer,
+        "%%MatrixMarket matrix coordinate {} general",
+        T::FIELD
+    )?;
+    writeln!(writer, "{} {} {}", matrix.rows, matrix.cols, matrix.nnz())?;
+    for (i, j, value) in matrix.entries() {
+        writeln!(writer, "{} {} {}", i + 1, j + 1, value)?;
+    }
+    Ok(())
+}
+
+fn parse_banner(banner: &str) -> Result<(Format, Symmetry)> {
+    let banner = banner.to_lowercase();
+    let tokens = banner.split_whitespace().collect::<Vec<_>>();
+    let (format, field, symmetry) = match &tokens[..] {
+        &["%%matrixmarket", "matrix", format, field, symmetry] => (format, field, symmetry),
+        _ => {
+            return Err(IoError::Malformed {
+                line: 1,
+                reason: "expected a %%MatrixMarket matrix banner",
+            })
+        }
+    };
+    let unsupported = |feature: &str| IoError::Unsupported {
+        line: 1,
+        feature: feature.to_string(),
+    };
+
+    let format = match format {
+        "coordinate" => Format::Coordinate,
+        "array" => Format::Array,
+        other => return Err(unsupported(other)),
+    };
+    if field != "real" && field != "integer" {
+        return Err(unsupported(field));
+    }
+    let symmetry = match symmetry {
+        "general" => Symmetry::General,
+        "symmetric" => Symmetry::Symmetric,
+        "skew-symmetric" => Symmetry::SkewSymmetric,
+        other => return Err(unsupported(other)),
+    };
+    Ok((format, symmetry))
+}
+
+// Number of entries an array file stores for a rows x cols matrix, or None if
+// that overflows.
+fn array_len(rows: usize, cols: usize, symmetry: Symmetry) -> Option<usize> {
+    // One of n and n ± 1 is even, so halve that before multiplying.
+    let triangle = |n: usize, m: usize| {
+        if n.is_multiple_of(2) {
+            (n / 2).checked_mul(m)
+        } else {
+            n.checked_mul(m / 2)
+        }
+    };
+    match symmetry {
+        Symmetry::General => rows.checked_mul(cols),
+        Symmetry::Symmetric => triangle(rows, rows.checked_add(1)?),
+        Symmetry::SkewSymmetric => triangle(rows, rows.saturating_sub(1)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mat, solver::gauss};
+
+    #[test]
+    fn test_round_trip() {
+        let a = mat![[1.5, 0.0, -2.0], [0.0, 1e-12, 3.0]];
+        let mut file = Vec::new();
+        write(&mut file, &a).unwrap();
+        assert_eq!(a, read::<f64, _>(&file[..]).unwrap());
+
+        let coo = read_coo::<f64, _>(&file[..]).unwrap();
+        assert_eq!(4, coo.nnz());
+        let mut file = Vec::new();
+        write_coo(&mut file, &coo).unwrap();
+        assert_eq!(a, read::<f64, _>(&file[..]).unwrap());
+    }
+
+    #[test]
+    fn test_skew_symmetric_array() {
+        let file = "%%MatrixMarket matrix array integer skew-symmetric\n3 3\n1\n2\n3\n";
+        assert_eq!(
+            mat![[0, -1, -2], [1, 0, -3], [2, 3, 0]],
+            read::<i32, _>(file.as_bytes()).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_solves_reference_system() {
+        // Augmented [A | b] for the 1D Laplacian with solution (1, 2, 3).
+        let file = "%%MatrixMarket matrix coordinate real general
+3 4 9
+1 1 2
+1 2 -1
+2 1 -1
+2 2 2
+2 3 -1
+3 2 -1
+3 3 2
+1 4 0
+3 4 4
+";
+        let system = read::<f64, _>(file.as_bytes()).unwrap();
+        let x = gauss::solve(system);
+        for (i, xi) in x.iter().enumerate() {
+            assert!((xi - (i + 1) as f64).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_malformed_input() {
+        let read = |file: &str| read::<f64, _>(file.as_bytes()).unwrap_err();
+        let banner = "%%MatrixMarket matrix coordinate real general\n";
+
+        assert!(matches!(read(""), IoError::Empty));
+        assert!(matches!(
+            read("%%MatrixMarket matrix coordinate complex general\n"),
+            IoError::Unsupported { line: 1, .. }
+        ));
+        assert!(matches!(
+            read(&format!("{}2 2\n", banner)),
+            IoError::Malformed { line: 2, .. }
+        ));
+        assert!(matches!(
+            read(&format!("{}2 2 1\n3 1 1.0\n", banner)),
+            IoError::OutOfBounds {
+                line: 3,
+                row: 3,
+                col: 1
+            }
+        ));
+        assert!(matches!(
+            read(&format!("{}2 2 2\n1 1 x\n", banner)),
+            IoError::Parse { line: 3, .. }
+        ));
+        assert!(matches!(
+            read(&format!("{}2 2 2\n1 1 1.0\n", banner)),
+            IoError::EntryCount {
+                expected: 2,
+                found: 1
+            }
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix array real general\n1 2\n1.0\n2.0\n3.0\n"),
+            IoError::EntryCount {
+                expected: 2,
+                found: 3
+            }
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix array real general\n18446744073709551615 2\n"),
+            IoError::Malformed { line: 2, .. }
+        ));
+        assert!(matches!(
+            read("%%MatrixMarket matrix array real symmetric\n18446744073709551615 18446744073709551615\n"),
+            IoError::Malformed { line: 2, .. }
+        ));
+        // A valid sparse header, far too large to hold densely.
+        assert!(matches!(
+            read(&format!("{}1000000 1000000 0\n", banner)),
+            IoError::TooLarge {
+                rows: 1000000,
+                cols: 1000000
+            }
+        ));
+        assert_eq!(
+            0,
+            read_coo::<f64, _>(format!("{}1000000 1000000 0\n", banner).as_bytes())
+                .unwrap()
+                .nnz()
+        );
+    }
+}
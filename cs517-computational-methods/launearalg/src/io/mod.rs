@@ -0,0 +1,103 @@
+//! Reading and writing matrices as text, in the Matrix Market exchange format
+//! and as delimited (CSV or TSV) tables.
+//!
+//! Readers take any `BufRead` and writers any `Write`, so a `File` needs
+//! wrapping in a `BufReader` or `BufWriter` first.
+use std::fmt;
+
+pub mod delimited;
+pub mod matrix_market;
+
+/// Reasons a matrix could not be read or written. Line numbers count from 1.
+#[derive(Debug)]
+pub enum IoError {
+    /// The underlying reader or writer failed.
+    Io(std::io::Error),
+    /// There were no entries to read.
+    Empty,
+    /// A line is not laid out the way the format requires.
+    Malformed { line: usize, reason: &'static str },
+    /// The Matrix Market banner names a format, field or symmetry which isn't
+    /// supported, such as `pattern` or `complex`.
+    Unsupported { line: usize, feature: String },
+    /// A field could not be parsed as an entry.
+    Parse { line: usize, token: String },
+    /// A coordinate entry, 1-based as in the file, is outside the matrix.
+    OutOfBounds { line: usize, row: usize, col: usize },
+    /// A row has a different number of fields from the first.
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+    /// The number of entries differs from the number the header declares.
+    EntryCount { expected: usize, found: usize },
+    /// The header declares a matrix too large to hold densely in memory.
+    TooLarge { rows: usize, cols: usize },
+}
+
+pub type Result<T> = std::result::Result<T, IoError>;
+
+impl std::error::Error for IoError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            IoError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for IoError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::result::Result<(), fmt::Error> {
+        match self {
+            IoError::Io(e) => write!(f, "{}", e),
+            IoError::Empty => write!(f, "No entries to read"),
+            IoError::Malformed { line, reason } => write!(f, "Line {}: {}", line, reason),
+            IoError::Unsupported { line, feature } => {
+                write!(f, "Line {}: {} matrices are not supported", line, feature)
+            }
+            IoError::Parse { line, token } => {
+                write!(f, "Line {}: could not parse {:?} as an entry", line, token)
+            }
+            IoError::OutOfBounds { line, row, col } => {
+                write!(
+                    f,
+                    "Line {}: entry ({}, {}) is out of bounds",
+                    line, row, col
+                )
+            }
+            IoError::Ragged {
+                line,
+                expected,
+                found,
+            } => write!(
+                f,
+                "Line {}: expected {} fields, found {}",
+                line, expected, found
+            ),
+            IoError::EntryCount { expected, found } => {
+                write!(f, "Expected {} entries, found {}", expected, found)
+            }
+            IoError::TooLarge { rows, cols } => {
+                write!(f, "A {} x {} matrix is too large to read", rows, cols)
+            }
+        }
+    }
+}
+
+impl From<std::io::Error> for IoError {
+    fn from(e: std::io::Error) -> Self {
+        IoError::Io(e)
+    }
+}
+
+// Parses one field, reporting the line it came from on failure.
+fn parse<T>(token: &str, line: usize) -> Result<T>
+where
+    T: std::str::FromStr,
+{
+    token.parse().map_err(|_| IoError::Parse {
+        line,
+        token: token.to_string(),
+    })
+}
@@ -4,16 +4,48 @@
 //! Its API is designed for convenience over raw performance, and applications perferring a
 //! lighter footprint should consider the [`ndarray`](https://crates.io/ndarray) crate.
 //!
-//! `launearalg` makes an opinionated trade off; choosing to use double the memory and a
-//! highly ineffecient Vec<Vec<T>> internal representations in return for a clean, natural
-//! API (I hope).
+//! `launearalg` keeps each `Matrix` in a single row-major buffer. Rows are borrowed as
+//! slices (`matrix[i]`), single entries with `matrix[(i, j)]`, and columns are gathered
+//! on demand with `matrix.col(j)`, so there is never a second copy to keep in sync.
+//! Rectangular blocks are borrowed, again without copying, with `matrix.view(rows, cols)`.
+//!
+//! For small systems whose size is known up front, `smatrix::SMatrix` keeps its entries on
+//! the stack and checks shapes at compile time.
+//!
+//! Triangular, symmetric and diagonal matrices can be wrapped in the types of `structured`,
+//! whose `solve` runs the routine suited to them, such as the substitutions of
+//! `solver::triangular`.
+//!
+//! The Krylov solvers in `solver::iterative` take any `operator::LinearOperator`, so a
+//! system can be given by closures applying a stencil, and never stored at all.
+//!
+//! Multiplication runs a cache-blocked kernel. With the `parallel` feature,
+//! `Matrix::par_mul` also spreads the rows of a product across rayon's thread pool.
+//!
+//! The `serde` feature makes `Matrix`, `Vector` and the approximation and interpolation
+//! solutions serializable, so a fit can be saved and reloaded for `evaluate`. Matrices themselves are read and
+//! written as Matrix Market or CSV files with the `io` module.
+
+#[macro_use]
+mod macros;
 
 pub mod approximator;
+pub mod banded;
+pub mod error;
 pub mod interpolater;
+pub mod io;
+mod matmul;
 pub mod matrix;
+pub mod operator;
+pub mod smatrix;
 pub mod solver;
+pub mod sparse;
+pub mod structured;
+#[cfg(test)]
+mod test_util;
 pub mod traits;
 pub mod vector;
+pub mod view;
 
 pub mod impl_slice;
 mod impl_vec_vec;
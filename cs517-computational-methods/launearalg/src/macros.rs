@@ -0,0 +1,58 @@
+// Implements the owned-operand forms of a binary operator in terms of the
+// `&lhs op &rhs` impl, so that `a + b`, `a + &b` and `&a + b` all work once
+// `&a + &b` does.
+macro_rules! forward_ref_binop {
+    (impl $imp:ident, $method:ident for $lhs:ident, $rhs:ident, $out:ident) => {
+        impl<T> $imp<$rhs<T>> for $lhs<T>
+        where
+            T: Num + Copy,
+        {
+            type Output = $out<T>;
+            fn $method(self, rhs: $rhs<T>) -> $out<T> {
+                (&self).$method(&rhs)
+            }
+        }
+
+        impl<T> $imp<&$rhs<T>> for $lhs<T>
+        where
+            T: Num + Copy,
+        {
+            type Output = $out<T>;
+            fn $method(self, rhs: &$rhs<T>) -> $out<T> {
+                (&self).$method(rhs)
+            }
+        }
+
+        impl<T> $imp<$rhs<T>> for &$lhs<T>
+        where
+            T: Num + Copy,
+        {
+            type Output = $out<T>;
+            fn $method(self, rhs: $rhs<T>) -> $out<T> {
+                self.$method(&rhs)
+            }
+        }
+    };
+}
+
+// Implements `n * A` for concrete scalar types, which a generic impl can't
+// provide because the scalar type would be uncovered.
+macro_rules! scalar_lhs_mul {
+    ($container:ident: $($t:ty),*) => {
+        $(
+            impl Mul<&$container<$t>> for $t {
+                type Output = $container<$t>;
+                fn mul(self, rhs: &$container<$t>) -> $container<$t> {
+                    rhs * self
+                }
+            }
+
+            impl Mul<$container<$t>> for $t {
+                type Output = $container<$t>;
+                fn mul(self, rhs: $container<$t>) -> $container<$t> {
+                    &rhs * self
+                }
+            }
+        )*
+    };
+}
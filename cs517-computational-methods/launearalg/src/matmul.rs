@@ -0,0 +1,114 @@
+// Kernels behind `Matrix` multiplication, on row-major slices.
+//
+// Every kernel accumulates each output entry as ((0 + a₀b₀) + a₁b₁) + …, with k
+// ascending, so they all agree bit for bit with the textbook triple loop, even
+// for floating point.
+use num_traits::Num;
+
+// Side of the square tiles the blocked kernel works on. 64 x 64 f64s is 32KiB,
+// so a tile of each operand sits in L2 on anything we run on.
+const BLOCK: usize = 64;
+
+// out += A B, for A m x n (row-major, so m = a.len() / n) and B n x p.
+pub(crate) fn blocked<T>(a: &[T], b: &[T], out: &mut [T], n: usize, p: usize)
+where
+    T: Num + Copy,
+{
+    let m = a.len() / n;
+    for ii in (0..m).step_by(BLOCK) {
+        for kk in (0..n).step_by(BLOCK) {
+            for jj in (0..p).step_by(BLOCK) {
+                let j_end = (jj + BLOCK).min(p);
+                for i in ii..(ii + BLOCK).min(m) {
+                    let out_row = &mut out[i * p + jj..i * p + j_end];
+                    for k in kk..(kk + BLOCK).min(n) {
+                        let aik = a[i * n + k];
+                        let b_row = &b[k * p + jj..k * p + j_end];
+                        for (o, &bkj) in out_row.iter_mut().zip(b_row) {
+                            *o = *o + aik * bkj;
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+// out = A Bᵀ, for A m x n and Bᵀ given as B, p x n. Each entry is a dot
+// product of two contiguous rows.
+pub(crate) fn transposed<T>(a: &[T], bt: &[T], out: &mut [T], n: usize)
+where
+    T: Num + Copy,
+{
+    let p = bt.len() / n;
+    for (a_row, out_row) in a.chunks(n).zip(out.chunks_mut(p)) {
+        for (o, bt_row) in out_row.iter_mut().zip(bt.chunks(n)) {
+            *o = a_row
+                .iter()
+                .zip(bt_row)
+                .fold(T::zero(), |acc, (&x, &y)| acc + x * y);
+        }
+    }
+}
+
+// out += A B, with the rows of `out` shared out across rayon's thread pool.
+#[cfg(feature = "parallel")]
+pub(crate) fn parallel<T>(a: &[T], b: &[T], out: &mut [T], n: usize, p: usize)
+where
+    T: Num + Copy + Send + Sync,
+{
+    use rayon::prelude::*;
+
+    out.par_chunks_mut(p)
+        .zip(a.par_chunks(n))
+        .for_each(|(out_row, a_row)| blocked(a_row, b, out_row, n, p));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{matrix::Matrix, traits::Transpose};
+
+    fn reference<T: Num + Copy>(a: &Matrix<T>, b: &Matrix<T>) -> Matrix<T> {
+        let mut result = Matrix::new(a.rows, b.cols);
+        for i in 0..a.rows {
+            for j in 0..b.cols {
+                for k in 0..a.cols {
+                    result[i][j] = result[i][j] + a[i][k] * b[k][j];
+                }
+            }
+        }
+        result
+    }
+
+    // Sizes straddling the tile edges.
+    const SHAPES: [(usize, usize, usize); 4] = [(1, 1, 1), (3, 70, 2), (65, 64, 129), (130, 7, 66)];
+
+    #[test]
+    fn test_kernels_match_reference_for_integers() {
+        for &(m, n, p) in SHAPES.iter() {
+            let a = Matrix::from_fn(m, n, |i, j| (i * 7 + j * 3) as i64 % 11 - 5);
+            let b = Matrix::from_fn(n, p, |i, j| (i * 5 + j) as i64 % 13 - 6);
+            let expected = reference(&a, &b);
+
+            assert_eq!(expected, &a * &b);
+            assert_eq!(expected, a.mul_transposed(&b.transpose()));
+            #[cfg(feature = "parallel")]
+            assert_eq!(expected, a.par_mul(&b));
+        }
+    }
+
+    #[test]
+    fn test_kernels_match_reference_bitwise_for_floats() {
+        for &(m, n, p) in SHAPES.iter() {
+            let a: Matrix<f64> = Matrix::random(m, n, 1);
+            let b = Matrix::random(n, p, 2);
+            let expected = reference(&a, &b);
+
+            assert_eq!(expected, &a * &b);
+            assert_eq!(expected, a.mul_transposed(&b.transpose()));
+            #[cfg(feature = "parallel")]
+            assert_eq!(expected, a.par_mul(&b));
+        }
+    }
+}
@@ -1,23 +1,36 @@
-use num_traits::Num;
-use std::ops::{Index, IndexMut, Mul};
+use num_traits::{Float, Num, NumAssignOps, Signed};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign};
 
 use crate::{
-    row,
+    error::{LinalgError, Result},
+    matmul,
+    solver::{gauss, lu::LuFactorization},
     traits::{Augment, Transpose},
     vector::Col,
-    vector::Row,
     vector::Vector,
+    view::ColIter,
 };
 
 #[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(try_from = "MatrixData<T>")
+)]
 /// High level struct describing a 2D matrix
+///
+/// Entries are kept in a single contiguous, row-major buffer. Rows are handed out
+/// as slices into that buffer, and columns are gathered on demand, so there is
+/// only ever one copy of the data to keep up to date.
+///
+/// With the `serde` feature, a `Matrix` serializes as its `rows`, `cols` and
+/// row-major `data`, and deserializing checks that the three agree.
 pub struct Matrix<T> {
     /// Number of rows for instance of Matrix
     pub rows: usize,
     /// Number of columns for instance of Matrix
     pub cols: usize,
-    data_rows: Vec<Row<T>>,
-    data_cols: Vec<Col<T>>,
+    data: Vec<T>,
 }
 
 impl<T> Matrix<T>
@@ -45,79 +58,855 @@ where
         Matrix {
             rows,
             cols,
-            data_rows: vec![row![T::zero(); cols]; rows],
-            data_cols: vec![row![T::zero(); rows]; cols],
+            data: vec![T::zero(); rows * cols],
+        }
+    }
+
+    /// Fallible version of [`new`](#method.new), returning `LinalgError::Empty`
+    /// rather than panicking if rows or cols ≦ 0.
+    pub fn try_new(rows: usize, cols: usize) -> Result<Self> {
+        if rows == 0 || cols == 0 {
+            return Err(LinalgError::Empty);
+        }
+        Ok(Matrix::new(rows, cols))
+    }
+
+    // A zeroed rows x cols `Matrix` of a size read from untrusted input,
+    // or None if it is empty or its entries can't be allocated, rather than
+    // aborting.
+    pub(crate) fn try_zeroed(rows: usize, cols: usize) -> Option<Self> {
+        let len = rows.checked_mul(cols).filter(|len| *len > 0)?;
+        let mut data = Vec::new();
+        data.try_reserve_exact(len).ok()?;
+        data.resize(len, T::zero());
+        Some(Matrix { rows, cols, data })
+    }
+
+    /// Creates a new `Matrix` from a `Vec<Vec<T>>` of rows, returning an error
+    /// rather than panicking if there are no entries, or the rows differ in length.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{error::LinalgError, matrix::Matrix};
+    ///
+    /// let ragged = Matrix::<u8>::try_from_rows(vec![vec![1, 2, 3], vec![4, 5]]);
+    /// assert_eq!(
+    ///     Err(LinalgError::DimensionMismatch { expected: (1, 3), found: (1, 2) }),
+    ///     ragged
+    /// );
+    /// ```
+    pub fn try_from_rows(v: Vec<Vec<T>>) -> Result<Self> {
+        let cols = v.first().map_or(0, |row| row.len());
+        if cols == 0 {
+            return Err(LinalgError::Empty);
+        }
+        if let Some(row) = v.iter().find(|row| row.len() != cols) {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (1, cols),
+                found: (1, row.len()),
+            });
+        }
+
+        Ok(Matrix {
+            rows: v.len(),
+            cols,
+            data: v.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Checks that no entry is NaN or infinite, reporting the first that is.
+    ///
+    /// Integer matrices always pass.
+    #[allow(clippy::eq_op)]
+    pub fn check_finite(&self) -> Result<()> {
+        // x - x is zero for every finite x, and NaN for NaN and ±∞, which keeps
+        // this usable for any `Num` rather than only floats.
+        match self.data.iter().position(|x| *x - *x != T::zero()) {
+            Some(i) => Err(LinalgError::NonFinite {
+                row: i / self.cols,
+                col: i % self.cols,
+            }),
+            None => Ok(()),
+        }
+    }
+
+    /// Fallible version of [`augment`](#impl-Augment<%26Matrix<T>>), returning an
+    /// error rather than panicking if `b` does not have as many rows as `self`.
+    pub fn try_augment(&self, b: &Matrix<T>) -> Result<Matrix<T>> {
+        if self.rows != b.rows {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (self.rows, b.cols),
+                found: (b.rows, b.cols),
+            });
+        }
+
+        let data = self
+            .iter()
+            .zip(b.iter())
+            .flat_map(|(ra, rb)| ra.iter().chain(rb.iter()).copied())
+            .collect();
+
+        Ok(Matrix {
+            rows: self.rows,
+            cols: self.cols + b.cols,
+            data,
+        })
+    }
+
+    /// Fallible version of [`Mul`](#impl-Mul<%26Matrix<T>>), returning an error
+    /// rather than panicking if `rhs` does not have as many rows as `self` has columns.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, error::LinalgError, matrix::Matrix};
+    ///
+    /// let a = mat![[1, 2, 3], [4, 5, 6]];
+    /// let b = mat![[1, 2], [3, 4]];
+    /// assert_eq!(
+    ///     Err(LinalgError::DimensionMismatch { expected: (3, 2), found: (2, 2) }),
+    ///     a.try_mul(&b)
+    /// );
+    /// ```
+    pub fn try_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        if self.cols != rhs.rows {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (self.cols, rhs.cols),
+                found: (rhs.rows, rhs.cols),
+            });
+        }
+
+        let mut result = Matrix::new(self.rows, rhs.cols);
+        matmul::blocked(&self.data, &rhs.data, &mut result.data, self.cols, rhs.cols);
+        Ok(result)
+    }
+
+    /// Fallible version of [`mul_transposed`](#method.mul_transposed), returning
+    /// an error rather than panicking if `rhs_t` does not have as many columns as
+    /// `self`.
+    pub fn try_mul_transposed(&self, rhs_t: &Matrix<T>) -> Result<Matrix<T>> {
+        if self.cols != rhs_t.cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (rhs_t.rows, self.cols),
+                found: (rhs_t.rows, rhs_t.cols),
+            });
+        }
+
+        let mut result = Matrix::new(self.rows, rhs_t.rows);
+        matmul::transposed(&self.data, &rhs_t.data, &mut result.data, self.cols);
+        Ok(result)
+    }
+
+    /// The product A Bᵀ, taking B already transposed, i.e. `&a * &b.transpose()`
+    /// without making the transpose.
+    ///
+    /// Both operands are read a row at a time, so this is the quickest way to
+    /// multiply when the right-hand side is at hand in transposed form, as in
+    /// the normal equations AᵀA. The result is identical to `*`.
+    ///
+    /// # Panics
+    /// Panics if `rhs_t` does not have as many columns as `self`. See
+    /// [`try_mul_transposed`](#method.try_mul_transposed) for a fallible version.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix, traits::Transpose};
+    ///
+    /// let a = mat![[1, 2, 3], [4, 5, 6]];
+    /// let b = mat![[4, 5], [6, 7], [8, 9]];
+    /// assert_eq!(&a * &b, a.mul_transposed(&b.transpose()));
+    /// ```
+    pub fn mul_transposed(&self, rhs_t: &Matrix<T>) -> Matrix<T> {
+        self.try_mul_transposed(rhs_t).unwrap()
+    }
+
+    /// Fallible version of [`Add`](#impl-Add<%26Matrix<T>>), returning an error
+    /// rather than panicking if `rhs` is not the same shape as `self`.
+    pub fn try_add(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        let mut sum = self.clone();
+        sum.try_zip_in_place(rhs, |a, b| a + b)?;
+        Ok(sum)
+    }
+
+    /// Fallible version of [`Sub`](#impl-Sub<%26Matrix<T>>), returning an error
+    /// rather than panicking if `rhs` is not the same shape as `self`.
+    pub fn try_sub(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        let mut difference = self.clone();
+        difference.try_zip_in_place(rhs, |a, b| a - b)?;
+        Ok(difference)
+    }
+
+    /// Fallible version of [`hadamard`](#method.hadamard), returning an error
+    /// rather than panicking if `rhs` is not the same shape as `self`.
+    pub fn try_hadamard(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        let mut product = self.clone();
+        product.try_zip_in_place(rhs, |a, b| a * b)?;
+        Ok(product)
+    }
+
+    /// The Hadamard, or entry-wise, product of two `Matrix`es of the same shape.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is not the same shape as `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let a = mat![[1, 2], [3, 4]];
+    /// assert_eq!(mat![[1, 4], [9, 16]], a.hadamard(&a));
+    /// ```
+    pub fn hadamard(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.try_hadamard(rhs).unwrap()
+    }
+
+    /// Fallible version of [`Mul<&Vector>`](#impl-Mul<%26Vector<T>>), returning an
+    /// error rather than panicking if `rhs` does not have as many entries as
+    /// `self` has columns.
+    pub fn try_mul_vector(&self, rhs: &Vector<T>) -> Result<Vector<T>> {
+        if self.cols != rhs.len() {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (self.cols, 1),
+                found: (rhs.len(), 1),
+            });
+        }
+
+        Ok(self
+            .iter()
+            .map(|row| {
+                row.iter()
+                    .zip(rhs.iter())
+                    .fold(T::zero(), |acc, (a, x)| acc + *a * *x)
+            })
+            .collect())
+    }
+
+    // Replaces each entry a of `self` with op(a, b) for the matching entry b of `rhs`.
+    fn try_zip_in_place<F>(&mut self, rhs: &Matrix<T>, op: F) -> Result<()>
+    where
+        F: Fn(T, T) -> T,
+    {
+        if (self.rows, self.cols) != (rhs.rows, rhs.cols) {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (self.rows, self.cols),
+                found: (rhs.rows, rhs.cols),
+            });
+        }
+        for (a, b) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *a = op(*a, *b);
+        }
+        Ok(())
+    }
+
+    /// Returns an iterator over the rows of the `Matrix`, each as a slice.
+    pub fn iter(&self) -> std::slice::Chunks<'_, T> {
+        self.data.chunks(self.cols)
+    }
+
+    /// Returns an iterator over the rows of the `Matrix`, each as a slice. The
+    /// same as [`iter`](#method.iter).
+    pub fn rows(&self) -> std::slice::Chunks<'_, T> {
+        self.iter()
+    }
+
+    /// Returns an iterator over the rows of the `Matrix`, each as a mutable slice.
+    pub fn rows_mut(&mut self) -> std::slice::ChunksMut<'_, T> {
+        self.data.chunks_mut(self.cols)
+    }
+
+    /// Returns an iterator over the columns of the `Matrix`, each as an iterator
+    /// over its entries, without copying them out as [`col`](#method.col) does.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let a = mat![[1, 2], [3, 4]];
+    /// let sums: Vec<i32> = a.cols().map(|col| col.sum()).collect();
+    /// assert_eq!(vec![4, 6], sums);
+    /// ```
+    pub fn cols(&self) -> impl Iterator<Item = ColIter<'_, T>> {
+        (0..self.cols).map(move |j| self.data[j..].iter().step_by(self.cols))
+    }
+
+    /// Returns an iterator over ((row, col), entry) in row-major order.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let a = mat![[1, 9], [3, 4]];
+    /// assert_eq!(Some(((0, 1), &9)), a.indexed_iter().max_by_key(|(_, x)| **x));
+    /// ```
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        let cols = self.cols;
+        self.data
+            .iter()
+            .enumerate()
+            .map(move |(k, x)| ((k / cols, k % cols), x))
+    }
+
+    /// Returns an iterator over ((row, col), entry) in row-major order, with
+    /// each entry mutable.
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        let cols = self.cols;
+        self.data
+            .iter_mut()
+            .enumerate()
+            .map(move |(k, x)| ((k / cols, k % cols), x))
+    }
+
+    /// Borrows every entry, row after row.
+    pub fn as_slice(&self) -> &[T] {
+        &self.data
+    }
+
+    /// Mutably borrows every entry, row after row.
+    pub fn as_mut_slice(&mut self) -> &mut [T] {
+        &mut self.data
+    }
+
+    /// Borrows row `i` of the `Matrix` as a slice.
+    ///
+    /// Equivalent to `&matrix[i]`.
+    pub fn row(&self, i: usize) -> &[T] {
+        &self[i]
+    }
+
+    /// Copies column `j` of the `Matrix` out into a new [`Col`](../vector/type.Col.html).
+    ///
+    /// Columns are not stored separately, they are gathered from the row-major
+    /// buffer each time this is called, and so always reflect the current state
+    /// of the `Matrix`.
+    ///
+    /// # Panics
+    /// Panics if `j` is not a valid column index.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, row, matrix::Matrix, vector::Row};
+    ///
+    /// let mut a = mat![[1, 2], [3, 4]];
+    /// a[0][0] = 10;
+    ///
+    /// assert_eq!(row![10, 3], a.col(0));
+    /// ```
+    pub fn col(&self, j: usize) -> Col<T> {
+        assert!(j < self.cols);
+        Vector::from(
+            self.data
+                .iter()
+                .skip(j)
+                .step_by(self.cols)
+                .copied()
+                .collect::<Vec<T>>(),
+        )
+    }
+
+    /// Returns a new matrix whose rows with given indices are swapped.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let a = mat![[1,2,3], [3,2,1]];
+    /// assert_eq!(mat![[3, 2, 1], [1, 2, 3]], a.swap_rows(0, 1));
+    /// ```
+    pub fn swap_rows(&self, r1: usize, r2: usize) -> Matrix<T> {
+        let mut swapped = self.clone();
+        swapped.swap_rows_in_place(r1, r2);
+        swapped
+    }
+
+    /// Swaps the rows with given indices without allocating.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let mut a = mat![[1,2,3], [3,2,1]];
+    /// a.swap_rows_in_place(0, 1);
+    /// assert_eq!(mat![[3, 2, 1], [1, 2, 3]], a);
+    /// ```
+    pub fn swap_rows_in_place(&mut self, r1: usize, r2: usize) {
+        if r1 == r2 {
+            return;
+        }
+
+        let (lo, hi) = (r1.min(r2), r1.max(r2));
+        let (head, tail) = self.data.split_at_mut(hi * self.cols);
+        head[lo * self.cols..(lo + 1) * self.cols].swap_with_slice(&mut tail[..self.cols]);
+    }
+
+    /// The sum of the diagonal entries of a square `Matrix`.
+    ///
+    /// # Panics
+    /// Panics if the `Matrix` is not square.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// assert_eq!(5, mat![[1, 2], [3, 4]].trace());
+    /// ```
+    pub fn trace(&self) -> T {
+        assert_eq!(self.rows, self.cols, "trace of a non-square matrix");
+        (0..self.rows).fold(T::zero(), |acc, i| acc + self[i][i])
+    }
+
+    /// Raises a square `Matrix` to the power `n` by repeated squaring, taking
+    /// O(log n) matrix products. `pow(0)` is the identity.
+    ///
+    /// # Panics
+    /// Panics if the `Matrix` is not square.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// // Fibonacci numbers
+    /// let f = mat![[1, 1], [1, 0]];
+    /// assert_eq!(mat![[89, 55], [55, 34]], f.pow(10));
+    /// ```
+    pub fn pow(&self, mut n: u32) -> Matrix<T> {
+        assert_eq!(self.rows, self.cols, "power of a non-square matrix");
+
+        let mut result = Matrix::identity(self.rows);
+        let mut square = self.clone();
+        while n > 0 {
+            if n & 1 == 1 {
+                result = &result * &square;
+            }
+            n >>= 1;
+            if n > 0 {
+                square = &square * &square;
+            }
+        }
+        result
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// Create the n x n identity `Matrix`.
+    ///
+    /// # Panics
+    /// Panics if n ≦ 0
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// assert_eq!(mat![[1, 0], [0, 1]], Matrix::identity(2));
+    /// ```
+    pub fn identity(n: usize) -> Self {
+        Matrix::from_fn(n, n, |i, j| if i == j { T::one() } else { T::zero() })
+    }
+
+    /// Create a square `Matrix` with `diag` on its diagonal and zeros elsewhere.
+    ///
+    /// # Panics
+    /// Panics if `diag` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// assert_eq!(mat![[1, 0], [0, 2]], Matrix::from_diag(&[1, 2]));
+    /// ```
+    pub fn from_diag(diag: &[T]) -> Self {
+        let n = diag.len();
+        Matrix::from_fn(n, n, |i, j| if i == j { diag[i] } else { T::zero() })
+    }
+
+    /// Create a rows x cols `Matrix` whose entry (i, j) is `f(i, j)`. Entries
+    /// are visited in row-major order.
+    ///
+    /// # Panics
+    /// Panics if rows or cols ≦ 0
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let hilbert = Matrix::from_fn(2, 2, |i, j| 1.0 / (i + j + 1) as f64);
+    /// assert_eq!(mat![[1.0, 0.5], [0.5, 1.0 / 3.0]], hilbert);
+    /// ```
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        assert!(rows > 0 && cols > 0);
+        Matrix {
+            rows,
+            cols,
+            data: (0..rows * cols).map(|k| f(k / cols, k % cols)).collect(),
+        }
+    }
+
+    /// Create the Vandermonde `Matrix` for the sample points `xs`, with one row
+    /// [1, x, x², …, x^degree] per point. This is the design matrix for fitting
+    /// a polynomial of the given degree by least squares.
+    ///
+    /// # Panics
+    /// Panics if `xs` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// assert_eq!(mat![[1, 2, 4], [1, 3, 9]], Matrix::vandermonde(&[2, 3], 2));
+    /// ```
+    pub fn vandermonde(xs: &[T], degree: usize) -> Self {
+        let mut v = Matrix::new(xs.len(), degree + 1);
+        for (i, x) in xs.iter().enumerate() {
+            let mut power = T::one();
+            for entry in v[i].iter_mut() {
+                *entry = power;
+                power = power * *x;
+            }
+        }
+        v
+    }
+
+    /// Join `Matrix`es with the same number of rows side by side, left to right.
+    ///
+    /// Returns an error if `blocks` is empty or their row counts differ.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let a = mat![[1], [2]];
+    /// let b = mat![[3, 4], [5, 6]];
+    /// assert_eq!(Ok(mat![[1, 3, 4], [2, 5, 6]]), Matrix::hstack(&[&a, &b]));
+    /// ```
+    pub fn hstack(blocks: &[&Matrix<T>]) -> Result<Self> {
+        let (first, rest) = blocks.split_first().ok_or(LinalgError::Empty)?;
+        rest.iter()
+            .try_fold((*first).clone(), |acc, block| acc.try_augment(block))
+    }
+
+    /// Join `Matrix`es with the same number of columns one above the other, top
+    /// to bottom.
+    ///
+    /// Returns an error if `blocks` is empty or their column counts differ.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let a = mat![[1, 2]];
+    /// let b = mat![[3, 4], [5, 6]];
+    /// assert_eq!(Ok(mat![[1, 2], [3, 4], [5, 6]]), Matrix::vstack(&[&a, &b]));
+    /// ```
+    pub fn vstack(blocks: &[&Matrix<T>]) -> Result<Self> {
+        let cols = blocks.first().ok_or(LinalgError::Empty)?.cols;
+        if let Some(block) = blocks.iter().find(|block| block.cols != cols) {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (block.rows, cols),
+                found: (block.rows, block.cols),
+            });
+        }
+
+        Ok(Matrix {
+            rows: blocks.iter().map(|block| block.rows).sum(),
+            cols,
+            data: blocks
+                .iter()
+                .flat_map(|block| block.data.iter().copied())
+                .collect(),
+        })
+    }
+
+    /// Assemble a `Matrix` from a grid of blocks, given as rows of blocks. Every
+    /// block in a block row must have the same number of rows, and every block
+    /// row the same total number of columns.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let i = Matrix::identity(2);
+    /// let z = Matrix::new(2, 2);
+    /// let a = mat![[1, 2], [3, 4]];
+    ///
+    /// let block = Matrix::block(&[&[&i, &a], &[&z, &i]]).unwrap();
+    /// assert_eq!(mat![[1, 0, 1, 2], [0, 1, 3, 4], [0, 0, 1, 0], [0, 0, 0, 1]], block);
+    /// ```
+    pub fn block(blocks: &[&[&Matrix<T>]]) -> Result<Self> {
+        let block_rows = blocks
+            .iter()
+            .map(|row| Matrix::hstack(row))
+            .collect::<Result<Vec<Matrix<T>>>>()?;
+        Matrix::vstack(&block_rows.iter().collect::<Vec<&Matrix<T>>>())
+    }
+
+    /// The Kronecker product A ⊗ B, the mp x nq block `Matrix` whose (i, j)
+    /// block is aᵢⱼB, for A of size m x n and B of size p x q.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let a = mat![[1, 2]];
+    /// let b = mat![[1, 0], [0, 1]];
+    /// assert_eq!(mat![[1, 0, 2, 0], [0, 1, 0, 2]], a.kron(&b));
+    /// ```
+    pub fn kron(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        Matrix::from_fn(self.rows * rhs.rows, self.cols * rhs.cols, |i, j| {
+            self[i / rhs.rows][j / rhs.cols] * rhs[i % rhs.rows][j % rhs.cols]
+        })
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Float,
+{
+    /// Create a rows x cols `Matrix` of pseudo-random entries drawn uniformly
+    /// from [0, 1). The same `seed` always gives the same `Matrix`, on every
+    /// platform, which keeps tests and benchmarks reproducible.
+    ///
+    /// The generator is SplitMix64, which is fast and well distributed but
+    /// **not** suitable for anything security sensitive.
+    ///
+    /// # Panics
+    /// Panics if rows or cols ≦ 0
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::matrix::Matrix;
+    ///
+    /// let a: Matrix<f64> = Matrix::random(3, 3, 42);
+    /// assert_eq!(a, Matrix::random(3, 3, 42));
+    /// assert!(a.iter().flatten().all(|x| (0.0..1.0).contains(x)));
+    /// ```
+    pub fn random(rows: usize, cols: usize, seed: u64) -> Self {
+        let mut state = seed;
+        // Keep only as many of the top bits as T has mantissa bits, p = 53 for
+        // f64 and 24 for f32, so every draw converts to T exactly and none can
+        // round up to 1. ε = 2¹⁻ᵖ, so ε / 2 maps them onto [0, 1).
+        let bits = (T::one() - T::epsilon().log2()).to_u32().unwrap().min(64);
+        let scale = T::epsilon() / (T::one() + T::one());
+        Matrix::from_fn(rows, cols, |_, _| {
+            state = state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+            let mut z = state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+            z ^= z >> 31;
+            T::from(z >> (64 - bits)).unwrap() * scale
+        })
+    }
+}
+
+/// Assembles a `Matrix` a row at a time, for when the number of rows isn't
+/// known up front.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::{Matrix, MatrixBuilder}};
+///
+/// let mut builder = MatrixBuilder::new();
+/// for x in 0..3 {
+///     builder.push_row(&[1, x]).unwrap();
+/// }
+/// assert_eq!(Ok(mat![[1, 0], [1, 1], [1, 2]]), builder.build());
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct MatrixBuilder<T> {
+    cols: usize,
+    data: Vec<T>,
+}
+
+impl<T> MatrixBuilder<T>
+where
+    T: Num + Copy,
+{
+    /// Create a `MatrixBuilder` with no rows. The first row pushed fixes the
+    /// number of columns.
+    pub fn new() -> Self {
+        MatrixBuilder {
+            cols: 0,
+            data: Vec::new(),
+        }
+    }
+
+    /// Number of rows pushed so far.
+    pub fn rows(&self) -> usize {
+        self.data.len().checked_div(self.cols).unwrap_or(0)
+    }
+
+    /// Append a row.
+    ///
+    /// Returns an error if `row` is empty, or not as long as the rows before it.
+    pub fn push_row(&mut self, row: &[T]) -> Result<()> {
+        if row.is_empty() {
+            return Err(LinalgError::Empty);
+        }
+        if self.cols == 0 {
+            self.cols = row.len();
+        } else if row.len() != self.cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (1, self.cols),
+                found: (1, row.len()),
+            });
         }
+        self.data.extend_from_slice(row);
+        Ok(())
+    }
+
+    /// Finish building, returning `LinalgError::Empty` if no rows were pushed.
+    pub fn build(self) -> Result<Matrix<T>> {
+        if self.data.is_empty() {
+            return Err(LinalgError::Empty);
+        }
+        Ok(Matrix {
+            rows: self.data.len() / self.cols,
+            cols: self.cols,
+            data: self.data,
+        })
+    }
+}
+
+impl<T> Default for MatrixBuilder<T>
+where
+    T: Num + Copy,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Float + Signed + NumAssignOps,
+{
+    /// The determinant of a square `Matrix`, by LU decomposition with partial
+    /// pivoting.
+    ///
+    /// Elimination divides, so this is only for floating point entries; for
+    /// integer entries use [`bareiss::determinant`](../solver/bareiss/fn.determinant.html),
+    /// which is exact.
+    ///
+    /// Returns an error if the `Matrix` is not square, or has a NaN or infinite entry.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// assert_eq!(Ok(-2.0), mat![[1.0, 2.0], [3.0, 4.0]].determinant());
+    /// assert_eq!(Ok(0.0), mat![[1.0, 2.0], [2.0, 4.0]].determinant());
+    /// ```
+    ///
+    /// Integer entries do not compile:
+    /// ```compile_fail
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let determinant = mat![[2i64, 1], [1, 2]].determinant();
+    /// ```
+    pub fn determinant(&self) -> Result<T> {
+        match LuFactorization::new(self) {
+            Ok(lu) => Ok(lu.determinant()),
+            Err(LinalgError::Singular { .. }) => Ok(T::zero()),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// The inverse A⁻¹, by Gauss–Jordan elimination. See [`gauss::inverse`](../solver/gauss/fn.inverse.html).
+    pub fn inverse(&self) -> Result<Matrix<T>> {
+        gauss::inverse(self)
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Float,
+{
+    /// The Frobenius norm, √(Σ aᵢⱼ²).
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let a: Matrix<f64> = mat![[1.0, -2.0], [-2.0, 4.0]];
+    /// assert_eq!(5.0, a.norm_fro());
+    /// ```
+    pub fn norm_fro(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, x| acc.hypot(*x))
     }
 
-    /// Syncronizes the column representation of the Matrix with the
-    /// row representation.
-    ///
-    /// This is an ugly hack due to time constraints and a desire
-    /// to allow arithmatic operators on rows vs some API like
-    /// matrix /= (row index, n) ≍ matrix[i] /= n.
-    /// It places the onus on users to call after they've finished
-    /// some mutable manipulation of their matrix at the row or element
-    /// level. Cols are only exposed as immutable, and so this works.
+    /// The 1-norm, the largest absolute column sum.
     ///
     /// # Example
     /// ```
-    /// use launearalg::{mat, vector::Vector, matrix::Matrix};
-    /// let mut a = mat![[1, 1, 1], [1, 1, 1]];
-    /// a[0] /= 2;
-    /// a.sync();
+    /// use launearalg::{mat, matrix::Matrix};
     ///
-    /// assert_eq!(mat![[0, 0, 0], [1,1,1]], a);
+    /// let a: Matrix<f64> = mat![[1.0, -2.0], [-3.0, 4.0]];
+    /// assert_eq!(6.0, a.norm_1());
+    /// assert_eq!(7.0, a.norm_inf());
     /// ```
-    pub fn sync(&mut self) {
-        self.data_cols = self.data_rows.transpose()
+    pub fn norm_1(&self) -> T {
+        (0..self.cols)
+            .map(|j| self.iter().fold(T::zero(), |acc, row| acc + row[j].abs()))
+            .fold(T::zero(), T::max)
     }
 
-    pub fn iter(&self) -> std::slice::Iter<Vector<T>> {
-        self.data_rows.iter()
+    /// The ∞-norm, the largest absolute row sum.
+    pub fn norm_inf(&self) -> T {
+        self.iter()
+            .map(|row| row.iter().fold(T::zero(), |acc, x| acc + x.abs()))
+            .fold(T::zero(), T::max)
     }
+}
 
-    /// Returns a new matrix whose rows with given indices are swapped.
+#[cfg(feature = "parallel")]
+impl<T> Matrix<T>
+where
+    T: Num + Copy + Send + Sync,
+{
+    /// Fallible version of [`par_mul`](#method.par_mul), returning an error
+    /// rather than panicking if `rhs` does not have as many rows as `self` has columns.
+    pub fn try_par_mul(&self, rhs: &Matrix<T>) -> Result<Matrix<T>> {
+        if self.cols != rhs.rows {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (self.cols, rhs.cols),
+                found: (rhs.rows, rhs.cols),
+            });
+        }
+
+        let mut result = Matrix::new(self.rows, rhs.cols);
+        matmul::parallel(&self.data, &rhs.data, &mut result.data, self.cols, rhs.cols);
+        Ok(result)
+    }
+
+    /// Matrix multiplication with the rows of the result computed in parallel
+    /// on rayon's global thread pool. Needs the `parallel` feature.
+    ///
+    /// The result is identical to `*`; the spawning only pays for itself once
+    /// the product has a few hundred thousand multiply-adds in it.
+    ///
+    /// # Panics
+    /// Panics if `rhs` does not have as many rows as `self` has columns. See
+    /// [`try_par_mul`](#method.try_par_mul) for a fallible version.
     ///
     /// # Example
     /// ```
     /// use launearalg::{mat, matrix::Matrix};
     ///
-    /// let a = mat![[1,2,3], [3,2,1]];
-    /// assert_eq!(mat![[3, 2, 1], [1, 2, 3]], a.swap_rows(0, 1));
+    /// let a = mat![[1, 2, 3], [4, 5, 6]];
+    /// let b = mat![[4, 5], [6, 7], [8, 9]];
+    /// assert_eq!(&a * &b, a.par_mul(&b));
     /// ```
-    pub fn swap_rows(&self, r1: usize, r2: usize) -> Matrix<T>
-    where
-        T: Copy,
-    {
-        let mut data_rows = self.data_rows.clone();
-        Matrix::swapsies(&mut data_rows, r1, r2);
-        let data_cols = data_rows.transpose();
-
-        Matrix {
-            rows: self.rows,
-            cols: self.cols,
-            data_rows,
-            data_cols,
-        }
-    }
-
-    // The great thing about private members is you can name them whatever you want
-    // and as long as the next person who comes along can understand what you wrote,
-    // you're squared away!
-    fn swapsies(data: &mut Vec<Vector<T>>, r1: usize, r2: usize)
-    where
-        T: Copy,
-    {
-        let tmp = data[r1].clone();
-        data[r1] = data[r2].clone();
-        data[r2] = tmp;
+    pub fn par_mul(&self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.try_par_mul(rhs).unwrap()
     }
 }
 
 impl<T> Transpose for Matrix<T>
 where
-    T: Clone,
+    T: Copy,
 {
     /// Create a new `Matrix` B, which is the transpose of `Matrix` A,
     /// that is to say `A[i][j] == B[j][i]`.
@@ -133,11 +922,14 @@ where
     /// ```
     type Output = Matrix<T>;
     fn transpose(&self) -> Self::Output {
+        let data = (0..self.cols)
+            .flat_map(|j| self.data.iter().skip(j).step_by(self.cols).copied())
+            .collect();
+
         Matrix {
             rows: self.cols,
             cols: self.rows,
-            data_rows: self.data_cols.clone(),
-            data_cols: self.data_rows.clone(),
+            data,
         }
     }
 }
@@ -147,23 +939,11 @@ where
     T: Num + Copy,
 {
     type Output = Self;
+    /// # Panics
+    /// Panics if `b` does not have as many rows as `self`. See
+    /// [`try_augment`](#method.try_augment) for a fallible version.
     fn augment(&self, b: &Matrix<T>) -> Matrix<T> {
-        assert!(self.rows == b.rows);
-        let augmented = self
-            .data_rows
-            .iter()
-            .zip(b.data_rows.iter())
-            .map(|(ra, rb)| ra.augment(rb))
-            .collect::<Vec<Vector<T>>>();
-
-        let aug_t = augmented.transpose();
-
-        Matrix {
-            rows: self.rows,
-            cols: self.cols + b.cols,
-            data_rows: augmented,
-            data_cols: aug_t,
-        }
+        self.try_augment(b).unwrap()
     }
 }
 
@@ -174,6 +954,10 @@ where
     /// Creates a new `Matrix` A from a `Vec<Vec<T>>` where each Vec<T> represents
     /// a row of the young `Matrix`.
     ///
+    /// # Panics
+    /// Panics if there are no entries, or the rows are not all of the same length.
+    /// See [`try_from_rows`](#method.try_from_rows) for a fallible version.
+    ///
     /// # Example
     /// ```
     /// use launearalg::{mat, matrix::Matrix};
@@ -182,12 +966,38 @@ where
     /// assert_eq!(mat![[1, 2, 3], [4, 5, 6]], a);
     /// ```
     fn from(v: Vec<Vec<T>>) -> Self {
-        Matrix {
-            rows: v.len(),
-            cols: v[0].len(),
-            data_rows: v.iter().map(Vector::from).collect(),
-            data_cols: v.transpose().iter().map(Vector::from).collect(),
+        Matrix::try_from_rows(v).unwrap()
+    }
+}
+
+// A `Matrix` as it was serialized, before its shape has been checked.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct MatrixData<T> {
+    rows: usize,
+    cols: usize,
+    data: Vec<T>,
+}
+
+#[cfg(feature = "serde")]
+impl<T> std::convert::TryFrom<MatrixData<T>> for Matrix<T> {
+    type Error = LinalgError;
+    fn try_from(m: MatrixData<T>) -> Result<Self> {
+        if m.rows == 0 || m.cols == 0 {
+            return Err(LinalgError::Empty);
         }
+        // The shape is untrusted, so rows x cols may not even fit in a usize.
+        if m.rows.checked_mul(m.cols) != Some(m.data.len()) {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (m.rows, m.cols),
+                found: (m.data.len(), 1),
+            });
+        }
+        Ok(Matrix {
+            rows: m.rows,
+            cols: m.cols,
+            data: m.data,
+        })
     }
 }
 
@@ -195,7 +1005,7 @@ impl<T> Index<usize> for Matrix<T>
 where
     T: Num + Copy,
 {
-    type Output = Vector<T>;
+    type Output = [T];
     /// # Example
     /// ```
     /// use launearalg::{mat, matrix::Matrix};
@@ -206,7 +1016,8 @@ where
     /// let row = &matrix[0];
     /// ```
     fn index(&self, index: usize) -> &Self::Output {
-        &self.data_rows[index]
+        assert!(index < self.rows);
+        &self.data[index * self.cols..(index + 1) * self.cols]
     }
 }
 
@@ -214,31 +1025,47 @@ impl<T> IndexMut<usize> for Matrix<T>
 where
     T: Num + Copy,
 {
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let mut matrix = mat![[1,2],[3,4]];
+    ///
+    /// // Mutates a single row of `matrix`
+    /// matrix[0].iter_mut().for_each(|x| *x *= 2);
+    /// assert_eq!(mat![[2, 4], [3, 4]], matrix);
+    /// ```
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        &mut self.data_rows[index]
+        assert!(index < self.rows);
+        &mut self.data[index * self.cols..(index + 1) * self.cols]
     }
 }
 
-impl<T> Index<std::ops::RangeFull> for Matrix<T>
+impl<T> Index<(usize, usize)> for Matrix<T>
 where
     T: Num + Copy,
 {
-    type Output = [Col<T>];
+    type Output = T;
     /// # Example
     /// ```
-    /// use launearalg::{mat, row, matrix::Matrix, vector::Row};
-    ///
-    /// let expected = vec![row![1, 3], row![2, 4]];
+    /// use launearalg::{mat, matrix::Matrix};
     ///
     /// let matrix = mat![[1,2],[3,4]];
     ///
-    /// // Accesses columns of `matrix`
-    /// let cols = &matrix[..];
-    ///
-    /// let first_col = &matrix[..][0];
+    /// // Accesses the entry at row 1, column 0 of `matrix`
+    /// assert_eq!(3, matrix[(1, 0)]);
     /// ```
-    fn index(&self, _index: std::ops::RangeFull) -> &Self::Output {
-        &self.data_cols[..]
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        &self[i][j]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        &mut self[i][j]
     }
 }
 
@@ -251,7 +1078,8 @@ where
     /// where A is of size m x n, B is of size n x p, and C is of size m x p.
     ///
     /// # Panics
-    /// Panics when B is not of size n x p for A of size m x n.
+    /// Panics when B is not of size n x p for A of size m x n. See
+    /// [`try_mul`](#method.try_mul) for a fallible version.
     ///
     /// # Example
     /// ```
@@ -271,18 +1099,7 @@ where
     /// assert_eq!(mat![[40, 46], [40, 46]], c);
     /// ```
     fn mul(self, rhs: &Matrix<T>) -> Matrix<T> {
-        let mut result = Matrix::new(self.rows, rhs.cols);
-
-        for i in 0..result.rows {
-            for j in 0..result.cols {
-                for k in 0..self.cols {
-                    let product = result[i][j] + self[i][k] * rhs[k][j];
-                    result.data_rows[i][j] = product;
-                    result.data_cols[j][i] = product;
-                }
-            }
-        }
-        result
+        self.try_mul(rhs).unwrap()
     }
 }
 
@@ -293,8 +1110,7 @@ where
     type Output = Matrix<T>;
     /// Perform scalar multiplication for A * n
     /// where A a matrix of <T>, and n is a scalar <T>.
-    /// Note: n must be the right-hand side of the equation.
-    /// ``` n * A``` will result in a compiler error.
+    /// For `f64` and `f32` entries, `n * A` works too.
     ///
     /// # Example
     /// ```
@@ -312,12 +1128,214 @@ where
         Matrix {
             rows: self.rows,
             cols: self.cols,
-            data_rows: self.data_rows.iter().map(|x| x * rhs).collect(),
-            data_cols: self.data_cols.iter().map(|x| x * rhs).collect(),
+            data: self.data.iter().map(|x| *x * rhs).collect(),
+        }
+    }
+}
+
+impl<T> Mul<T> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    type Output = Matrix<T>;
+    fn mul(self, rhs: T) -> Matrix<T> {
+        &self * rhs
+    }
+}
+
+scalar_lhs_mul!(Matrix: f64, f32);
+
+impl<T> Mul<&Vector<T>> for &Matrix<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+    /// Multiply the m x n `Matrix` A by the n entry `Vector` x, giving the m
+    /// entry `Vector` Ax.
+    ///
+    /// # Panics
+    /// Panics if x does not have n entries. See
+    /// [`try_mul_vector`](#method.try_mul_vector) for a fallible version.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, row, matrix::Matrix, vector::Row};
+    ///
+    /// let a = mat![[1, 2], [3, 4], [5, 6]];
+    /// assert_eq!(row![5, 11, 17], &a * &row![1, 2]);
+    /// ```
+    fn mul(self, rhs: &Vector<T>) -> Vector<T> {
+        self.try_mul_vector(rhs).unwrap()
+    }
+}
+
+impl<T> Add<&Matrix<T>> for &Matrix<T>
+where
+    T: Num + Copy,
+{
+    type Output = Matrix<T>;
+    /// Perform entry-wise addition of two `Matrix`es of the same shape.
+    ///
+    /// # Panics
+    /// Panics if B is not the same shape as A. See [`try_add`](#method.try_add)
+    /// for a fallible version.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let a = mat![[1, 2], [3, 4]];
+    /// let b = mat![[10, 20], [30, 40]];
+    ///
+    /// assert_eq!(mat![[11, 22], [33, 44]], &a + &b);
+    /// assert_eq!(mat![[11, 22], [33, 44]], a + b);
+    /// ```
+    fn add(self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.try_add(rhs).unwrap()
+    }
+}
+
+impl<T> Sub<&Matrix<T>> for &Matrix<T>
+where
+    T: Num + Copy,
+{
+    type Output = Matrix<T>;
+    /// Perform entry-wise subtraction of two `Matrix`es of the same shape.
+    ///
+    /// # Panics
+    /// Panics if B is not the same shape as A. See [`try_sub`](#method.try_sub)
+    /// for a fallible version.
+    fn sub(self, rhs: &Matrix<T>) -> Matrix<T> {
+        self.try_sub(rhs).unwrap()
+    }
+}
+
+forward_ref_binop!(impl Mul, mul for Matrix, Matrix, Matrix);
+forward_ref_binop!(impl Mul, mul for Matrix, Vector, Vector);
+forward_ref_binop!(impl Add, add for Matrix, Matrix, Matrix);
+forward_ref_binop!(impl Sub, sub for Matrix, Matrix, Matrix);
+
+impl<T> Neg for &Matrix<T>
+where
+    T: Num + Copy + Neg<Output = T>,
+{
+    type Output = Matrix<T>;
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let a = mat![[1, -2], [3, 0]];
+    /// assert_eq!(mat![[-1, 2], [-3, 0]], -&a);
+    /// ```
+    fn neg(self) -> Matrix<T> {
+        Matrix {
+            rows: self.rows,
+            cols: self.cols,
+            data: self.data.iter().map(|x| -*x).collect(),
         }
     }
 }
 
+impl<T> Neg for Matrix<T>
+where
+    T: Num + Copy + Neg<Output = T>,
+{
+    type Output = Matrix<T>;
+    fn neg(self) -> Matrix<T> {
+        -&self
+    }
+}
+
+impl<T> AddAssign<&Matrix<T>> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// # Panics
+    /// Panics if B is not the same shape as A.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let mut a = mat![[1, 2], [3, 4]];
+    /// a += &mat![[1, 1], [1, 1]];
+    /// assert_eq!(mat![[2, 3], [4, 5]], a);
+    /// ```
+    fn add_assign(&mut self, rhs: &Matrix<T>) {
+        self.try_zip_in_place(rhs, |a, b| a + b).unwrap();
+    }
+}
+
+impl<T> AddAssign<Matrix<T>> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    fn add_assign(&mut self, rhs: Matrix<T>) {
+        *self += &rhs;
+    }
+}
+
+impl<T> SubAssign<&Matrix<T>> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// # Panics
+    /// Panics if B is not the same shape as A.
+    fn sub_assign(&mut self, rhs: &Matrix<T>) {
+        self.try_zip_in_place(rhs, |a, b| a - b).unwrap();
+    }
+}
+
+impl<T> SubAssign<Matrix<T>> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    fn sub_assign(&mut self, rhs: Matrix<T>) {
+        *self -= &rhs;
+    }
+}
+
+impl<T> MulAssign<T> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// Scale every entry by n in place.
+    fn mul_assign(&mut self, rhs: T) {
+        self.data.iter_mut().for_each(|x| *x = *x * rhs);
+    }
+}
+
+impl<T> MulAssign<&Matrix<T>> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// Replace A with the matrix product AB.
+    ///
+    /// # Panics
+    /// Panics when B does not have as many rows as A has columns.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let mut a = mat![[1, 2], [3, 4]];
+    /// a *= &mat![[0, 1], [1, 0]];
+    /// assert_eq!(mat![[2, 1], [4, 3]], a);
+    /// ```
+    fn mul_assign(&mut self, rhs: &Matrix<T>) {
+        *self = &*self * rhs;
+    }
+}
+
+impl<T> MulAssign<Matrix<T>> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    fn mul_assign(&mut self, rhs: Matrix<T>) {
+        *self *= &rhs;
+    }
+}
+
 /// Create a new matrix with convenient C-like 2D array syntax
 ///
 /// # Example
@@ -340,6 +1358,7 @@ macro_rules! mat {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{row, vector::Row};
     #[test]
     #[should_panic]
     fn test_panic_on_0_size() {
@@ -355,13 +1374,23 @@ mod tests {
         assert_eq!(sut[0][1], 2);
         assert_eq!(sut[1][0], 3);
         assert_eq!(sut[1][1], 4);
-        assert_eq!(sut[0], row![1, 2]);
+        assert_eq!(sut[0], [1, 2]);
+        assert_eq!(sut[(1, 0)], 3);
     }
 
     #[test]
     fn test_index_column() {
         let sut = mat![[1, 2], [3, 4]];
-        assert_eq!(row![1, 3], sut[..][0]);
+        assert_eq!(row![1, 3], sut.col(0));
+    }
+
+    #[test]
+    fn test_column_follows_row_mutation() {
+        let mut sut = mat![[1, 2], [3, 4]];
+        sut[1][0] = 5;
+        sut[(0, 1)] = 7;
+        assert_eq!(row![1, 5], sut.col(0));
+        assert_eq!(row![7, 4], sut.col(1));
     }
 
     #[test]
@@ -452,10 +1481,233 @@ mod tests {
         let _c = a.augment(&b);
     }
 
+    #[test]
+    fn test_try_variants_report_bad_shapes() {
+        assert_eq!(Err(LinalgError::Empty), Matrix::<u8>::try_new(0, 1));
+        assert_eq!(Err(LinalgError::Empty), Matrix::<u8>::try_from_rows(vec![]));
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (3, 1),
+                found: (2, 1)
+            }),
+            mat![[1], [2], [3]].try_augment(&mat![[1], [2]])
+        );
+    }
+
+    #[test]
+    fn test_check_finite() {
+        assert_eq!(Ok(()), mat![[1, 2], [3, 4]].check_finite());
+        assert_eq!(Ok(()), mat![[1.0, 2.0], [3.0, 4.0]].check_finite());
+        assert_eq!(
+            Err(LinalgError::NonFinite { row: 1, col: 0 }),
+            mat![[1.0, 2.0], [f64::INFINITY, f64::NAN]].check_finite()
+        );
+    }
+
     #[test]
     fn test_swap_rows() {
         let sut = mat![[1, 2, 3], [3, 2, 1]];
         let expected = mat![[3, 2, 1], [1, 2, 3]];
         assert_eq!(expected, sut.swap_rows(0, 1));
     }
+
+    #[test]
+    fn test_swap_rows_in_place() {
+        let mut sut = mat![[1, 2], [3, 4], [5, 6]];
+        sut.swap_rows_in_place(2, 0);
+        assert_eq!(mat![[5, 6], [3, 4], [1, 2]], sut);
+        sut.swap_rows_in_place(1, 1);
+        assert_eq!(mat![[5, 6], [3, 4], [1, 2]], sut);
+    }
+
+    #[test]
+    fn test_elementwise_operators() {
+        let a = mat![[1, 2], [3, 4]];
+        let b = mat![[5, 6], [7, 8]];
+
+        assert_eq!(mat![[6, 8], [10, 12]], &a + &b);
+        assert_eq!(mat![[-4, -4], [-4, -4]], a.clone() - b.clone());
+        assert_eq!(mat![[-1, -2], [-3, -4]], -a.clone());
+        assert_eq!(mat![[5, 12], [21, 32]], a.hadamard(&b));
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 2),
+                found: (1, 2)
+            }),
+            a.try_add(&mat![[1, 2]])
+        );
+
+        let mut c = a.clone();
+        c += &b;
+        c -= a.clone();
+        assert_eq!(b, c);
+        c *= 2;
+        assert_eq!(mat![[10, 12], [14, 16]], c);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_add_panic_missize() {
+        let _ = mat![[1, 2]] + mat![[1], [2]];
+    }
+
+    #[test]
+    fn test_owned_and_scalar_lhs_products() {
+        let a = mat![[1.0, 2.0], [3.0, 4.0]];
+        let i = mat![[1.0, 0.0], [0.0, 1.0]];
+
+        assert_eq!(a, a.clone() * i.clone());
+        assert_eq!(a, &a * i.clone());
+        assert_eq!(&a * 2.0, 2.0 * &a);
+        assert_eq!(mat![[0.5_f32]], 0.5_f32 * mat![[1.0_f32]]);
+
+        let mut b = a.clone();
+        b *= i;
+        assert_eq!(a, b);
+    }
+
+    #[test]
+    fn test_matrix_vector_multiply() {
+        let a = mat![[1, 0, 2], [0, 3, 0]];
+        assert_eq!(row![7, 6], &a * &row![1, 2, 3]);
+        assert_eq!(row![7, 6], a.clone() * row![1, 2, 3]);
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (3, 1),
+                found: (2, 1)
+            }),
+            a.try_mul_vector(&row![1, 2])
+        );
+    }
+
+    #[test]
+    fn test_norms() {
+        let a = mat![[1.0, -7.0], [-2.0, -3.0]];
+        assert_eq!(10.0, a.norm_1());
+        assert_eq!(8.0, a.norm_inf());
+        assert!((a.norm_fro() - 63.0_f64.sqrt()).abs() < 1e-12);
+        // ‖A‖₂ is bounded by the other norms
+        let norm_2 = a.norm_2().unwrap();
+        assert!(norm_2 <= a.norm_fro());
+        assert!(norm_2 <= (a.norm_1() * a.norm_inf()).sqrt());
+    }
+
+    #[test]
+    fn test_determinant_and_inverse() {
+        let a = mat![[2.0, 0.0, 1.0], [1.0, 3.0, 2.0], [1.0, 1.0, 2.0]];
+        assert!((a.determinant().unwrap() - 6.0).abs() < 1e-12);
+        assert_eq!(
+            Err(LinalgError::NotSquare { rows: 1, cols: 2 }),
+            mat![[1.0, 2.0]].determinant()
+        );
+
+        let b = mat![[4.0, 7.0], [2.0, 6.0]];
+        let b_inv = b.inverse().unwrap();
+        assert_eq!(Ok(10.0), b.determinant());
+        assert!((b_inv.determinant().unwrap() - 0.1).abs() < 1e-12);
+        let expected = mat![[0.6, -0.7], [-0.2, 0.4]];
+        assert!((&expected - &b_inv).norm_fro() < 1e-12);
+    }
+
+    #[test]
+    fn test_trace_and_pow() {
+        let a = mat![[2, 1], [0, 3]];
+        assert_eq!(5, a.trace());
+        assert_eq!(mat![[1, 0], [0, 1]], a.pow(0));
+        assert_eq!(a, a.pow(1));
+        assert_eq!(&(&a * &a) * &a, a.pow(3));
+    }
+
+    #[test]
+    fn test_constructors() {
+        let i: Matrix<i32> = Matrix::identity(3);
+        assert_eq!(Matrix::from_diag(&[1, 1, 1]), i);
+        assert_eq!(3, i.trace());
+        assert_eq!(
+            mat![[0, 1, 2], [10, 11, 12]],
+            Matrix::from_fn(2, 3, |i, j| 10 * i + j)
+        );
+
+        let v = Matrix::vandermonde(&[1.0, 2.0, 3.0], 3);
+        assert_eq!((3, 4), (v.rows, v.cols));
+        assert_eq!(&[1.0, 3.0, 9.0, 27.0], &v[2]);
+
+        let r: Matrix<f32> = Matrix::random(4, 5, 7);
+        assert_ne!(r, Matrix::random(4, 5, 8));
+        assert!(r.iter().flatten().all(|x| (0.0..1.0).contains(x)));
+        // An f32 draw is the f64 draw truncated to 24 bits, never rounded up.
+        let r64: Matrix<f64> = Matrix::random(4, 5, 7);
+        let truncated = Matrix::from_fn(4, 5, |i, j| {
+            ((r64[i][j] * 2f64.powi(24)).floor() / 2f64.powi(24)) as f32
+        });
+        assert_eq!(truncated, r);
+    }
+
+    #[test]
+    fn test_stacking_rejects_bad_shapes() {
+        let a = mat![[1, 2]];
+        let b = mat![[1], [2]];
+
+        assert_eq!(Err(LinalgError::Empty), Matrix::<i32>::hstack(&[]));
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (1, 1),
+                found: (2, 1)
+            }),
+            Matrix::hstack(&[&a, &b])
+        );
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 2),
+                found: (2, 1)
+            }),
+            Matrix::vstack(&[&a, &b])
+        );
+        assert!(Matrix::block(&[&[&a], &[&b]]).is_err());
+    }
+
+    #[test]
+    fn test_kron_with_identity_is_block_diagonal() {
+        let a = mat![[1, 2], [3, 4]];
+        let z = Matrix::new(2, 2);
+        assert_eq!(
+            Matrix::block(&[&[&a, &z], &[&z, &a]]).unwrap(),
+            Matrix::identity(2).kron(&a)
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        let mut builder = MatrixBuilder::new();
+        assert_eq!(0, builder.rows());
+        builder.push_row(&[1.0, 2.0]).unwrap();
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (1, 2),
+                found: (1, 3)
+            }),
+            builder.push_row(&[1.0, 2.0, 3.0])
+        );
+        assert_eq!(1, builder.rows());
+        assert_eq!(Ok(mat![[1.0, 2.0]]), builder.build());
+        assert_eq!(Err(LinalgError::Empty), MatrixBuilder::<f64>::new().build());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let a = mat![[1.5, 2.0], [3.0, -4.25], [0.0, 1e-9]];
+        let json = serde_json::to_string(&a).unwrap();
+        assert_eq!(a, serde_json::from_str(&json).unwrap());
+
+        assert!(
+            serde_json::from_str::<Matrix<f64>>(r#"{"rows":2,"cols":2,"data":[1.0,2.0,3.0]}"#)
+                .is_err()
+        );
+        assert!(serde_json::from_str::<Matrix<f64>>(r#"{"rows":0,"cols":2,"data":[]}"#).is_err());
+        assert!(serde_json::from_str::<Matrix<f64>>(
+            r#"{"rows":18446744073709551615,"cols":2,"data":[]}"#
+        )
+        .is_err());
+    }
 }
@@ -0,0 +1,174 @@
+//! Linear operators: anything which can compute Ax and Aᵀx, whether or not A
+//! is ever stored.
+//!
+//! The Krylov solvers of [`solver::iterative`](../solver/iterative/index.html)
+//! need nothing more, so they accept a dense `Matrix`, the sparse and banded
+//! types, or an `FnOperator` defined by closures, such as a finite difference
+//! stencil which is never materialised.
+//!
+//! ```
+//! use launearalg::{operator::FnOperator, row, solver::iterative::{conjugate_gradient, IterativeSettings}, vector::{Row, Vector}};
+//!
+//! // The 1D Laplacian -u'' with zero boundary values, as the stencil [-1, 2, -1].
+//! let n = 50;
+//! let laplacian = FnOperator::symmetric(n, |u: &Vector<f64>| {
+//!     (0..n)
+//!         .map(|i| {
+//!             let left = if i > 0 { u[i - 1] } else { 0.0 };
+//!             let right = if i + 1 < n { u[i + 1] } else { 0.0 };
+//!             2.0 * u[i] - left - right
+//!         })
+//!         .collect()
+//! });
+//!
+//! let heat = Vector::from(vec![1.0; n]);
+//! let report = conjugate_gradient(&laplacian, &heat, &IterativeSettings::default()).unwrap();
+//! assert!(report.converged);
+//! ```
+use crate::{matrix::Matrix, vector::Vector};
+use num_traits::Num;
+
+/// A linear map from vectors of length `dims().1` to vectors of length
+/// `dims().0`.
+///
+/// Implemented by `Matrix`, the `sparse` and `banded` types, and `FnOperator`.
+/// Implementations may panic when given a vector of the wrong length; solvers
+/// check `dims` before applying an operator.
+pub trait LinearOperator<T> {
+    /// The shape (rows, cols).
+    fn dims(&self) -> (usize, usize);
+
+    /// Computes Ax.
+    fn apply(&self, x: &Vector<T>) -> Vector<T>;
+
+    /// Computes Aᵀx.
+    fn apply_transpose(&self, x: &Vector<T>) -> Vector<T>;
+}
+
+impl<T> LinearOperator<T> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn apply(&self, x: &Vector<T>) -> Vector<T> {
+        self * x
+    }
+
+    // Sums the rows weighted by x, without forming Aᵀ.
+    fn apply_transpose(&self, x: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.rows, x.len());
+
+        let mut y = vec![T::zero(); self.cols];
+        for (row, &xi) in self.rows().zip(x.iter()) {
+            for (yj, &aij) in y.iter_mut().zip(row.iter()) {
+                *yj = *yj + aij * xi;
+            }
+        }
+        Vector::from(y)
+    }
+}
+
+/// A `LinearOperator` given by closures computing Ax and Aᵀx.
+#[derive(Clone)]
+pub struct FnOperator<F, G = F> {
+    rows: usize,
+    cols: usize,
+    apply: F,
+    apply_transpose: G,
+}
+
+impl<F, G> FnOperator<F, G> {
+    /// Create a rows x cols operator computing Ax with `apply` and Aᵀx with
+    /// `apply_transpose`.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{operator::{FnOperator, LinearOperator}, row, vector::{Row, Vector}};
+    ///
+    /// // Sums adjacent pairs, [x₀ + x₁, x₁ + x₂].
+    /// let pairs = FnOperator::new(
+    ///     2,
+    ///     3,
+    ///     |x: &Vector<i32>| row![x[0] + x[1], x[1] + x[2]],
+    ///     |y: &Vector<i32>| row![y[0], y[0] + y[1], y[1]],
+    /// );
+    /// assert_eq!(row![3, 5], pairs.apply(&row![1, 2, 3]));
+    /// ```
+    pub fn new(rows: usize, cols: usize, apply: F, apply_transpose: G) -> Self {
+        FnOperator {
+            rows,
+            cols,
+            apply,
+            apply_transpose,
+        }
+    }
+}
+
+impl<F> FnOperator<F, F>
+where
+    F: Clone,
+{
+    /// Create an n x n symmetric operator, A = Aᵀ, computing both Ax and Aᵀx
+    /// with `apply`.
+    pub fn symmetric(n: usize, apply: F) -> Self {
+        FnOperator::new(n, n, apply.clone(), apply)
+    }
+}
+
+impl<T, F, G> LinearOperator<T> for FnOperator<F, G>
+where
+    F: Fn(&Vector<T>) -> Vector<T>,
+    G: Fn(&Vector<T>) -> Vector<T>,
+{
+    fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn apply(&self, x: &Vector<T>) -> Vector<T> {
+        (self.apply)(x)
+    }
+
+    fn apply_transpose(&self, x: &Vector<T>) -> Vector<T> {
+        (self.apply_transpose)(x)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        banded::{BandedMatrix, TridiagonalMatrix},
+        mat,
+        sparse::{CscMatrix, CsrMatrix},
+        traits::Transpose,
+    };
+
+    // Checks that `op` computes Ax and Aᵀx for the dense A.
+    fn assert_matches<A: LinearOperator<i64>>(a: &Matrix<i64>, op: &A) {
+        let x = Vector::from((1..=a.cols as i64).collect::<Vec<_>>());
+        let y = Vector::from((1..=a.rows as i64).map(|i| 3 - i).collect::<Vec<_>>());
+        assert_eq!((a.rows, a.cols), op.dims());
+        assert_eq!(a * &x, op.apply(&x));
+        assert_eq!(&a.transpose() * &y, op.apply_transpose(&y));
+    }
+
+    #[test]
+    fn test_every_representation_agrees() {
+        let a = mat![[4, -1, 0, 0], [2, 4, -1, 0], [0, 2, 4, -1], [0, 0, 2, 4]];
+        assert_matches(&a, &a);
+        assert_matches(&a, &CsrMatrix::from(&a));
+        assert_matches(&a, &CscMatrix::from(&a));
+
+        let tridiagonal =
+            TridiagonalMatrix::from_diagonals(vec![2, 2, 2], vec![4; 4], vec![-1, -1, -1]).unwrap();
+        assert_matches(&a, &tridiagonal);
+        assert_matches(&a, &BandedMatrix::from(&tridiagonal));
+
+        let rectangular = mat![[1, 2, 3], [4, 5, 6]];
+        assert_matches(&rectangular, &rectangular);
+        assert_matches(&rectangular, &CsrMatrix::from(&rectangular));
+    }
+}
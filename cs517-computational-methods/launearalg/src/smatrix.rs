@@ -0,0 +1,435 @@
+use crate::{
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    solver::gauss,
+    traits::Transpose,
+    vector::Vector,
+};
+use num_traits::{Float, Num};
+use std::{
+    convert::TryFrom,
+    ops::{Add, Index, IndexMut, Mul, Neg, Sub},
+};
+
+/// A stack-allocated R x C matrix whose dimensions are part of its type.
+///
+/// Products, augmentation and transposition only compile when the shapes
+/// agree, so the shape panics of the dynamically sized `Matrix` can't happen.
+/// Convert to and from `Matrix` to use the rest of the crate.
+///
+/// ```compile_fail
+/// use launearalg::smatrix::SMatrix;
+///
+/// let a = SMatrix::from([[1, 2, 3], [4, 5, 6]]);
+/// let b = SMatrix::from([[1, 2], [3, 4]]);
+/// let c = a * b; // 2x3 times 2x2 is rejected
+/// ```
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct SMatrix<T, const R: usize, const C: usize> {
+    data: [[T; C]; R],
+}
+
+/// A stack-allocated column vector of N entries, an N x 1 `SMatrix`.
+pub type SVector<T, const N: usize> = SMatrix<T, N, 1>;
+
+impl<T, const R: usize, const C: usize> SMatrix<T, R, C>
+where
+    T: Num + Copy,
+{
+    /// Create an R x C `SMatrix` of zeros.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::smatrix::SMatrix;
+    ///
+    /// let a = SMatrix::<f64, 2, 3>::zeros();
+    /// assert_eq!(0.0, a[(1, 2)]);
+    /// ```
+    pub fn zeros() -> Self {
+        SMatrix {
+            data: [[T::zero(); C]; R],
+        }
+    }
+
+    /// Create an `SMatrix` whose entry (i, j) is `f(i, j)`.
+    pub fn from_fn<F>(mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        let mut m = SMatrix::zeros();
+        for i in 0..R {
+            for j in 0..C {
+                m.data[i][j] = f(i, j);
+            }
+        }
+        m
+    }
+
+    /// Join `b` on to the right-hand side, giving [A | B].
+    ///
+    /// The width of the result, `CO`, is usually inferred, and must be C + C2;
+    /// anything else fails to compile.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::smatrix::{SMatrix, SVector};
+    ///
+    /// let a = SMatrix::from([[1, 2], [3, 4]]);
+    /// let b = SVector::from([[5], [6]]);
+    ///
+    /// let ab: SMatrix<i32, 2, 3> = a.augment(&b);
+    /// assert_eq!(SMatrix::from([[1, 2, 5], [3, 4, 6]]), ab);
+    /// ```
+    ///
+    /// ```compile_fail
+    /// use launearalg::smatrix::{SMatrix, SVector};
+    ///
+    /// let a = SMatrix::from([[1, 2], [3, 4]]);
+    /// let b = SVector::from([[5], [6]]);
+    /// let ab: SMatrix<i32, 2, 4> = a.augment(&b);
+    /// ```
+    pub fn augment<const C2: usize, const CO: usize>(
+        &self,
+        b: &SMatrix<T, R, C2>,
+    ) -> SMatrix<T, R, CO> {
+        #[allow(clippy::let_unit_value)]
+        let () = SumOf::<C, C2, CO>::CHECK;
+        SMatrix::from_fn(|i, j| {
+            if j < C {
+                self.data[i][j]
+            } else {
+                b.data[i][j - C]
+            }
+        })
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Num + Copy,
+{
+    /// Create the N x N identity `SMatrix`.
+    pub fn identity() -> Self {
+        SMatrix::from_fn(|i, j| if i == j { T::one() } else { T::zero() })
+    }
+}
+
+impl<T, const N: usize> SMatrix<T, N, N>
+where
+    T: Float,
+{
+    /// Solves Ax = b by Gaussian elimination with partial pivoting, entirely
+    /// on the stack.
+    ///
+    /// Returns `LinalgError::Singular` if a pivot within rounding error of zero,
+    /// |p| ≤ Nε·max|aᵢⱼ|, is met.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::smatrix::{SMatrix, SVector};
+    ///
+    /// let a = SMatrix::from([[2.0, 1.0], [1.0, 3.0]]);
+    /// let x = a.solve(&SVector::from([[5.0], [10.0]])).unwrap();
+    ///
+    /// assert_eq!(SVector::from([[1.0], [3.0]]), x);
+    /// ```
+    pub fn solve(&self, b: &SVector<T, N>) -> Result<SVector<T, N>> {
+        let mut a = self.data;
+        let mut x = b.data;
+        let max_abs = a
+            .iter()
+            .flatten()
+            .fold(T::zero(), |acc, x| acc.max(x.abs()));
+        let tolerance = gauss::float_pivot_tolerance(N, max_abs);
+
+        for k in 0..N {
+            let pivot_row = (k..N).fold(k, |best, i| {
+                if a[i][k].abs() > a[best][k].abs() {
+                    i
+                } else {
+                    best
+                }
+            });
+            if a[pivot_row][k].abs() <= tolerance {
+                return Err(LinalgError::Singular { pivot: k });
+            }
+            a.swap(k, pivot_row);
+            x.swap(k, pivot_row);
+
+            let (pivot, below) = a.split_at_mut(k + 1);
+            let pivot = &pivot[k];
+            for (i, row) in below.iter_mut().enumerate() {
+                let multiplier = row[k] / pivot[k];
+                for (entry, p) in row.iter_mut().zip(pivot.iter()).skip(k) {
+                    *entry = *entry - multiplier * *p;
+                }
+                x[k + 1 + i][0] = x[k + 1 + i][0] - multiplier * x[k][0];
+            }
+        }
+
+        for i in (0..N).rev() {
+            let sum = (i + 1..N).fold(x[i][0], |acc, j| acc - a[i][j] * x[j][0]);
+            x[i][0] = sum / a[i][i];
+        }
+
+        Ok(SMatrix { data: x })
+    }
+}
+
+// Compile-time check that A + B == SUM, evaluated when `CHECK` is used.
+struct SumOf<const A: usize, const B: usize, const SUM: usize>;
+
+impl<const A: usize, const B: usize, const SUM: usize> SumOf<A, B, SUM> {
+    const CHECK: () = assert!(
+        A + B == SUM,
+        "augmented width must be the sum of the widths"
+    );
+}
+
+impl<T, const R: usize, const C: usize> From<[[T; C]; R]> for SMatrix<T, R, C> {
+    /// Creates an `SMatrix` from an array of rows.
+    fn from(data: [[T; C]; R]) -> Self {
+        SMatrix { data }
+    }
+}
+
+impl<T, const R: usize, const C: usize> Transpose for SMatrix<T, R, C>
+where
+    T: Num + Copy,
+{
+    type Output = SMatrix<T, C, R>;
+    /// # Example
+    /// ```
+    /// use launearalg::{smatrix::SMatrix, traits::Transpose};
+    ///
+    /// let a = SMatrix::from([[1, 2, 3]]);
+    /// assert_eq!(SMatrix::from([[1], [2], [3]]), a.transpose());
+    /// ```
+    fn transpose(&self) -> Self::Output {
+        SMatrix::from_fn(|i, j| self.data[j][i])
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<(usize, usize)> for SMatrix<T, R, C> {
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        &self.data[i][j]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<(usize, usize)> for SMatrix<T, R, C> {
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        &mut self.data[i][j]
+    }
+}
+
+impl<T, const R: usize, const C: usize> Index<usize> for SMatrix<T, R, C> {
+    type Output = [T; C];
+    fn index(&self, i: usize) -> &Self::Output {
+        &self.data[i]
+    }
+}
+
+impl<T, const R: usize, const C: usize> IndexMut<usize> for SMatrix<T, R, C> {
+    fn index_mut(&mut self, i: usize) -> &mut Self::Output {
+        &mut self.data[i]
+    }
+}
+
+impl<T, const R: usize, const C: usize, const K: usize> Mul<SMatrix<T, C, K>> for SMatrix<T, R, C>
+where
+    T: Num + Copy,
+{
+    type Output = SMatrix<T, R, K>;
+    /// Perform matrix multiplication of an R x C by a C x K `SMatrix`.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::smatrix::{SMatrix, SVector};
+    ///
+    /// let a = SMatrix::from([[1, 2], [3, 4]]);
+    /// let x = SVector::from([[1], [1]]);
+    /// assert_eq!(SVector::from([[3], [7]]), a * x);
+    /// ```
+    fn mul(self, rhs: SMatrix<T, C, K>) -> Self::Output {
+        SMatrix::from_fn(|i, j| {
+            (0..C).fold(T::zero(), |acc, k| acc + self.data[i][k] * rhs.data[k][j])
+        })
+    }
+}
+
+impl<T, const R: usize, const C: usize> Mul<T> for SMatrix<T, R, C>
+where
+    T: Num + Copy,
+{
+    type Output = SMatrix<T, R, C>;
+    fn mul(self, rhs: T) -> Self::Output {
+        SMatrix::from_fn(|i, j| self.data[i][j] * rhs)
+    }
+}
+
+impl<T, const R: usize, const C: usize> Add for SMatrix<T, R, C>
+where
+    T: Num + Copy,
+{
+    type Output = SMatrix<T, R, C>;
+    fn add(self, rhs: SMatrix<T, R, C>) -> Self::Output {
+        SMatrix::from_fn(|i, j| self.data[i][j] + rhs.data[i][j])
+    }
+}
+
+impl<T, const R: usize, const C: usize> Sub for SMatrix<T, R, C>
+where
+    T: Num + Copy,
+{
+    type Output = SMatrix<T, R, C>;
+    fn sub(self, rhs: SMatrix<T, R, C>) -> Self::Output {
+        SMatrix::from_fn(|i, j| self.data[i][j] - rhs.data[i][j])
+    }
+}
+
+impl<T, const R: usize, const C: usize> Neg for SMatrix<T, R, C>
+where
+    T: Num + Copy + Neg<Output = T>,
+{
+    type Output = SMatrix<T, R, C>;
+    fn neg(self) -> Self::Output {
+        SMatrix::from_fn(|i, j| -self.data[i][j])
+    }
+}
+
+impl<T, const R: usize, const C: usize> From<SMatrix<T, R, C>> for Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// # Panics
+    /// Panics if R or C is 0, as a `Matrix` can't be empty.
+    fn from(m: SMatrix<T, R, C>) -> Self {
+        Matrix::from_fn(R, C, |i, j| m.data[i][j])
+    }
+}
+
+impl<T, const R: usize, const C: usize> TryFrom<&Matrix<T>> for SMatrix<T, R, C>
+where
+    T: Num + Copy,
+{
+    type Error = LinalgError;
+    /// Copies a `Matrix` into an `SMatrix`, returning an error if it isn't R x C.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix, smatrix::SMatrix};
+    /// use std::convert::TryFrom;
+    ///
+    /// let a = mat![[1, 2], [3, 4]];
+    /// let s = SMatrix::<i32, 2, 2>::try_from(&a).unwrap();
+    ///
+    /// assert_eq!(a, Matrix::from(s));
+    /// assert!(SMatrix::<i32, 3, 2>::try_from(&a).is_err());
+    /// ```
+    fn try_from(m: &Matrix<T>) -> Result<Self> {
+        if (m.rows, m.cols) != (R, C) {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (R, C),
+                found: (m.rows, m.cols),
+            });
+        }
+        Ok(SMatrix::from_fn(|i, j| m[i][j]))
+    }
+}
+
+impl<T, const N: usize> From<SVector<T, N>> for Vector<T>
+where
+    T: Num + Copy,
+{
+    /// # Panics
+    /// Panics if N is 0, as a `Vector` can't be empty.
+    fn from(v: SVector<T, N>) -> Self {
+        v.data.iter().map(|row| row[0]).collect()
+    }
+}
+
+impl<T, const N: usize> TryFrom<&Vector<T>> for SVector<T, N>
+where
+    T: Num + Copy,
+{
+    type Error = LinalgError;
+    /// Copies a `Vector` into an `SVector`, returning an error if it doesn't
+    /// have N entries.
+    fn try_from(v: &Vector<T>) -> Result<Self> {
+        if v.len() != N {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (N, 1),
+                found: (v.len(), 1),
+            });
+        }
+        Ok(SMatrix::from_fn(|i, _| v[i]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mat, row, vector::Row};
+
+    #[test]
+    fn test_products_match_dynamic_matrix() {
+        let a = SMatrix::from([[1, 2, 3], [4, 5, 6]]);
+        let b = SMatrix::from([[1, 0], [0, 1], [2, 2]]);
+
+        let product: SMatrix<i32, 2, 2> = a * b;
+        assert_eq!(&Matrix::from(a) * &Matrix::from(b), Matrix::from(product));
+        assert_eq!(a, a.transpose().transpose());
+        assert_eq!(a * 2, a + a);
+        assert_eq!(SMatrix::zeros(), a - a);
+        assert_eq!(a, SMatrix::identity() * a);
+        assert_eq!(a * -1, -a);
+    }
+
+    #[test]
+    fn test_solve_4x4() {
+        let a = SMatrix::from([
+            [4.0, 1.0, 0.0, 0.0],
+            [1.0, 4.0, 1.0, 0.0],
+            [0.0, 1.0, 4.0, 1.0],
+            [0.0, 0.0, 1.0, 4.0],
+        ]);
+        let x = SVector::from([[1.0], [-1.0], [2.0], [0.5]]);
+        let solved = a.solve(&(a * x)).unwrap();
+
+        for i in 0..4 {
+            assert!((solved[(i, 0)] - x[(i, 0)]).abs() < 1e-12);
+        }
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 1 }),
+            SMatrix::from([[1.0, 2.0], [2.0, 4.0]]).solve(&SVector::from([[1.0], [1.0]]))
+        );
+        // Rank 2, with the last pivot lost in rounding rather than exactly zero.
+        let rank_2 = SMatrix::from([[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]]);
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 2 }),
+            rank_2.solve(&SVector::from([[1.0], [1.0], [1.0]]))
+        );
+    }
+
+    #[test]
+    fn test_vector_conversions() {
+        let v = row![1, 2, 3];
+        let s = SVector::<i32, 3>::try_from(&v).unwrap();
+        assert_eq!(v, Vector::from(s));
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 1),
+                found: (3, 1)
+            }),
+            SVector::<i32, 2>::try_from(&v)
+        );
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (1, 3),
+                found: (2, 2)
+            }),
+            SMatrix::<i32, 1, 3>::try_from(&mat![[1, 2], [3, 4]])
+        );
+    }
+}
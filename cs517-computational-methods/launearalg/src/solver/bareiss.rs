@@ -0,0 +1,238 @@
+//! Exact elimination for integer matrices.
+//!
+//! [`gauss`](../gauss/index.html) divides each pivot row through by its pivot,
+//! which truncates for integer entries. Bareiss' fraction-free elimination
+//! instead replaces each entry by a 2 x 2 determinant divided by the previous
+//! pivot, a division which is always exact, so determinants, ranks and
+//! solutions come out exactly. Every intermediate entry is a minor of the
+//! input, so they stay as small as the answer allows, though they can still
+//! overflow a fixed-width integer.
+//!
+//! For exact arithmetic throughout, `gauss` also accepts `Ratio` entries.
+//!
+//! ```
+//! use launearalg::{mat, matrix::Matrix, solver::{bareiss::Ratio, gauss}};
+//!
+//! let r = |n| Ratio::from_integer(n);
+//! let a = mat![[r(3), r(1), r(1)], [r(1), r(3), r(2)]];
+//! assert_eq!(vec![Ratio::new(1, 8), Ratio::new(5, 8)], gauss::solve(a).into_iter().collect::<Vec<_>>());
+//! ```
+use crate::{
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    vector::Vector,
+};
+use num_integer::Integer;
+use num_traits::Num;
+
+pub use num_rational::Ratio;
+
+/// The exact determinant of a square `Matrix`.
+///
+/// Returns an error if `a` is not square.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, solver::bareiss};
+///
+/// let a: Matrix<i64> = mat![[2, 3, 1], [4, 1, -3], [1, 2, 5]];
+/// assert_eq!(Ok(-40), bareiss::determinant(&a));
+/// ```
+pub fn determinant<T>(a: &Matrix<T>) -> Result<T>
+where
+    T: Num + Copy,
+{
+    if a.rows != a.cols {
+        return Err(LinalgError::NotSquare {
+            rows: a.rows,
+            cols: a.cols,
+        });
+    }
+
+    let mut m = a.clone();
+    let echelon = eliminate(&mut m, a.cols);
+    if echelon.pivots.len() < a.rows {
+        return Ok(T::zero());
+    }
+    let det = m[a.rows - 1][a.cols - 1];
+    Ok(if echelon.negated {
+        T::zero() - det
+    } else {
+        det
+    })
+}
+
+/// The exact rank of a `Matrix`, the number of linearly independent rows.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, solver::bareiss};
+///
+/// let a: Matrix<i64> = mat![[1, 2, 3], [2, 4, 6], [1, 0, 1]];
+/// assert_eq!(2, bareiss::rank(&a));
+/// ```
+pub fn rank<T>(a: &Matrix<T>) -> usize
+where
+    T: Num + Copy,
+{
+    eliminate(&mut a.clone(), a.cols).pivots.len()
+}
+
+/// Solves Ax = b exactly, returning x as fractions in lowest terms.
+///
+/// Elimination is done in integers; only the back substitution needs fractions.
+///
+/// Returns an error if `a` is not square, `b` has the wrong length, or A is
+/// singular, naming the first column without a pivot.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, row, vector::Row, solver::bareiss::{self, Ratio}};
+///
+/// let a: Matrix<i64> = mat![[3, 1], [1, 3]];
+/// let x = bareiss::solve(&a, &row![1, 2]).unwrap();
+/// assert_eq!(vec![Ratio::new(1, 8), Ratio::new(5, 8)], x.iter().copied().collect::<Vec<_>>());
+/// ```
+pub fn solve<T>(a: &Matrix<T>, b: &Vector<T>) -> Result<Vector<Ratio<T>>>
+where
+    T: Integer + Copy,
+{
+    if a.rows != a.cols {
+        return Err(LinalgError::NotSquare {
+            rows: a.rows,
+            cols: a.cols,
+        });
+    }
+    if b.len() != a.rows {
+        return Err(LinalgError::DimensionMismatch {
+            expected: (a.rows, 1),
+            found: (b.len(), 1),
+        });
+    }
+
+    let n = a.rows;
+    let mut m = Matrix::from_fn(n, n + 1, |i, j| if j < n { a[i][j] } else { b[i] });
+    let echelon = eliminate(&mut m, n);
+    if let Some(pivot) = (0..n).find(|&k| echelon.pivots.get(k) != Some(&k)) {
+        return Err(LinalgError::Singular { pivot });
+    }
+
+    let mut x = vec![Ratio::from_integer(T::zero()); n];
+    for i in (0..n).rev() {
+        let sum = (i + 1..n).fold(Ratio::from_integer(m[i][n]), |acc, j| {
+            acc - x[j] * Ratio::from_integer(m[i][j])
+        });
+        x[i] = sum / Ratio::from_integer(m[i][i]);
+    }
+    Ok(Vector::from(x))
+}
+
+// The outcome of fraction-free elimination.
+struct Echelon {
+    // The column of each pivot, top row first.
+    pivots: Vec<usize>,
+    // Whether an odd number of row swaps was made.
+    negated: bool,
+}
+
+// Reduces `m` in place to fraction-free row echelon form, taking pivots from
+// the first `pivot_cols` columns but updating every column. Afterwards the
+// last pivot of a square matrix of full rank is its determinant, up to sign.
+fn eliminate<T>(m: &mut Matrix<T>, pivot_cols: usize) -> Echelon
+where
+    T: Num + Copy,
+{
+    let mut echelon = Echelon {
+        pivots: Vec::new(),
+        negated: false,
+    };
+    let mut previous = T::one();
+
+    for k in 0..pivot_cols {
+        let r = echelon.pivots.len();
+        if r == m.rows {
+            break;
+        }
+        let p = match (r..m.rows).find(|&i| !m[i][k].is_zero()) {
+            Some(p) => p,
+            None => continue,
+        };
+        if p != r {
+            m.swap_rows_in_place(r, p);
+            echelon.negated = !echelon.negated;
+        }
+
+        let pivot = m[r][k];
+        for i in r + 1..m.rows {
+            let factor = m[i][k];
+            for j in k + 1..m.cols {
+                m[i][j] = (pivot * m[i][j] - factor * m[r][j]) / previous;
+            }
+            m[i][k] = T::zero();
+        }
+        previous = pivot;
+        echelon.pivots.push(k);
+    }
+
+    echelon
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mat, solver::gauss};
+
+    // The n x n Hilbert matrix scaled by lcm(1..2n) to make it integer, which
+    // floating point elimination handles badly.
+    fn scaled_hilbert(n: usize) -> Matrix<i128> {
+        let lcm = (1..2 * n as i128).fold(1, |acc: i128, k| acc.lcm(&k));
+        Matrix::from_fn(n, n, |i, j| lcm / (i + j + 1) as i128)
+    }
+
+    #[test]
+    fn test_determinant_matches_exact_rational_elimination() {
+        let a = scaled_hilbert(5);
+        let det = determinant(&a).unwrap();
+
+        let ratio = Matrix::from_fn(5, 5, |i, j| Ratio::from_integer(a[i][j]));
+        let mut m = ratio.clone();
+        let mut product = Ratio::from_integer(1);
+        for k in 0..5 {
+            product *= m[k][k];
+            for i in k + 1..5 {
+                let factor = m[i][k] / m[k][k];
+                for j in k..5 {
+                    let scaled = factor * m[k][j];
+                    m[i][j] -= scaled;
+                }
+            }
+        }
+        assert_eq!(Ratio::from_integer(det), product);
+        assert_eq!(Ok(0), determinant(&mat![[1, 2], [2, 4]]));
+        assert_eq!(Ok(-1), determinant(&mat![[0, 1], [1, 0]]));
+    }
+
+    #[test]
+    fn test_rank_of_rectangular_matrices() {
+        assert_eq!(1, rank(&mat![[0, 0, 2], [0, 0, 4]]));
+        assert_eq!(2, rank(&mat![[1, 2], [2, 4], [0, 1]]));
+        assert_eq!(3, rank(&mat![[1, 0, 0, 5], [0, 0, 1, 2], [0, 1, 0, 0]]));
+    }
+
+    #[test]
+    fn test_solve_agrees_with_gauss_on_ratios() {
+        let a = scaled_hilbert(4);
+        let b = Vector::from(vec![1, -2, 3, 5]);
+        let x = solve(&a, &b).unwrap();
+
+        let augmented = Matrix::from_fn(4, 5, |i, j| {
+            Ratio::from_integer(if j < 4 { a[i][j] } else { b[i] })
+        });
+        assert_eq!(x, gauss::solve(augmented));
+
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 1 }),
+            solve(&mat![[1, 2], [2, 4]], &Vector::from(vec![1, 1]))
+        );
+    }
+}
@@ -0,0 +1,307 @@
+use crate::{
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    vector::Vector,
+};
+use num_traits::{Float, NumAssignOps};
+
+/// The decomposition A = LLᵀ of a symmetric positive-definite `Matrix` A,
+/// where L is lower triangular with a positive diagonal.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Cholesky<T> {
+    l: Matrix<T>,
+}
+
+impl<T> Cholesky<T>
+where
+    T: Float + NumAssignOps,
+{
+    /// Decompose the symmetric positive-definite `Matrix` `a`.
+    ///
+    /// Only the lower triangle of `a` takes part in the decomposition, which needs
+    /// about half the work of LU. A pivot too small to be distinguished from
+    /// rounding error is reported as `NotPositiveDefinite`, rather than producing
+    /// a factor full of huge or NaN entries.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix, solver::cholesky::Cholesky};
+    ///
+    /// let a = mat![[4.0, 2.0], [2.0, 5.0]];
+    /// let chol = Cholesky::new(&a).unwrap();
+    ///
+    /// assert_eq!(&mat![[2.0, 0.0], [1.0, 2.0]], chol.l());
+    /// ```
+    pub fn new(a: &Matrix<T>) -> Result<Self> {
+        check_symmetric(a)?;
+
+        let n = a.rows;
+        let tolerance = pivot_tolerance(a);
+        let mut l = Matrix::new(n, n);
+
+        for j in 0..n {
+            let mut d = a[j][j];
+            for k in 0..j {
+                d -= l[j][k] * l[j][k];
+            }
+            if d <= tolerance {
+                return Err(LinalgError::NotPositiveDefinite { pivot: j });
+            }
+            let d = d.sqrt();
+            l[j][j] = d;
+
+            for i in j + 1..n {
+                let mut s = a[i][j];
+                for k in 0..j {
+                    s -= l[i][k] * l[j][k];
+                }
+                l[i][j] = s / d;
+            }
+        }
+
+        Ok(Cholesky { l })
+    }
+
+    /// The lower triangular factor L.
+    pub fn l(&self) -> &Matrix<T> {
+        &self.l
+    }
+
+    /// Solve Ax = b for x by a forward substitution with L, then a back
+    /// substitution with Lᵀ.
+    ///
+    /// # Panics
+    /// Panics if `b` is not the same length as the order of A.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, row, matrix::Matrix, vector::Row, solver::cholesky::Cholesky};
+    ///
+    /// let a = mat![[4.0, 2.0], [2.0, 5.0]];
+    /// let chol = Cholesky::new(&a).unwrap();
+    ///
+    /// assert_eq!(row![1.0, 1.0], chol.solve(&row![6.0, 7.0]));
+    /// ```
+    pub fn solve(&self, b: &Vector<T>) -> Vector<T> {
+        let n = self.l.rows;
+        assert_eq!(n, b.len());
+
+        let mut x: Vec<T> = b.iter().copied().collect();
+        for i in 0..n {
+            for k in 0..i {
+                let scaled = self.l[i][k] * x[k];
+                x[i] -= scaled;
+            }
+            x[i] /= self.l[i][i];
+        }
+        for i in (0..n).rev() {
+            for k in i + 1..n {
+                let scaled = self.l[k][i] * x[k];
+                x[i] -= scaled;
+            }
+            x[i] /= self.l[i][i];
+        }
+
+        Vector::from(x)
+    }
+}
+
+/// The square-root free decomposition A = LDLᵀ of a symmetric `Matrix` A,
+/// where L is unit lower triangular and D is diagonal.
+///
+/// Unlike [`Cholesky`](struct.Cholesky.html) this does not require A to be
+/// positive definite, only that no zero pivot is met along the way.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Ldlt<T> {
+    l: Matrix<T>,
+    d: Vector<T>,
+}
+
+impl<T> Ldlt<T>
+where
+    T: Float + NumAssignOps,
+{
+    /// Decompose the symmetric `Matrix` `a`.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, row, matrix::Matrix, vector::Row, solver::cholesky::Ldlt};
+    ///
+    /// let a = mat![[4.0, 2.0], [2.0, -1.0]];
+    /// let ldlt = Ldlt::new(&a).unwrap();
+    ///
+    /// assert_eq!(&mat![[1.0, 0.0], [0.5, 1.0]], ldlt.l());
+    /// assert_eq!(&row![4.0, -2.0], ldlt.d());
+    /// ```
+    pub fn new(a: &Matrix<T>) -> Result<Self> {
+        check_symmetric(a)?;
+
+        let n = a.rows;
+        let tolerance = pivot_tolerance(a);
+        let mut l = Matrix::new(n, n);
+        let mut d = Vector::new(n);
+
+        for j in 0..n {
+            let mut dj = a[j][j];
+            for k in 0..j {
+                dj -= l[j][k] * l[j][k] * d[k];
+            }
+            if dj.abs() <= tolerance {
+                return Err(LinalgError::Singular { pivot: j });
+            }
+            d[j] = dj;
+            l[j][j] = T::one();
+
+            for i in j + 1..n {
+                let mut s = a[i][j];
+                for k in 0..j {
+                    s -= l[i][k] * l[j][k] * d[k];
+                }
+                l[i][j] = s / dj;
+            }
+        }
+
+        Ok(Ldlt { l, d })
+    }
+
+    /// The unit lower triangular factor L.
+    pub fn l(&self) -> &Matrix<T> {
+        &self.l
+    }
+
+    /// The diagonal of D.
+    pub fn d(&self) -> &Vector<T> {
+        &self.d
+    }
+
+    /// Whether every entry of D is positive, that is whether A is positive definite.
+    pub fn is_positive_definite(&self) -> bool {
+        self.d.iter().all(|d| *d > T::zero())
+    }
+
+    /// Solve Ax = b for x by substituting through L, D and Lᵀ in turn.
+    ///
+    /// # Panics
+    /// Panics if `b` is not the same length as the order of A.
+    pub fn solve(&self, b: &Vector<T>) -> Vector<T> {
+        let n = self.l.rows;
+        assert_eq!(n, b.len());
+
+        let mut x: Vec<T> = b.iter().copied().collect();
+        for i in 0..n {
+            for k in 0..i {
+                let scaled = self.l[i][k] * x[k];
+                x[i] -= scaled;
+            }
+        }
+        x.iter_mut().zip(self.d.iter()).for_each(|(x, d)| *x /= *d);
+        for i in (0..n).rev() {
+            for k in i + 1..n {
+                let scaled = self.l[k][i] * x[k];
+                x[i] -= scaled;
+            }
+        }
+
+        Vector::from(x)
+    }
+}
+
+// Checks that `a` is square, finite and symmetric to within rounding error.
+pub(crate) fn check_symmetric<T>(a: &Matrix<T>) -> Result<()>
+where
+    T: Float,
+{
+    if a.rows != a.cols {
+        return Err(LinalgError::NotSquare {
+            rows: a.rows,
+            cols: a.cols,
+        });
+    }
+    a.check_finite()?;
+
+    let tolerance = T::epsilon() * T::from(a.rows).unwrap();
+    for i in 0..a.rows {
+        for j in 0..i {
+            let (upper, lower) = (a[j][i], a[i][j]);
+            if (upper - lower).abs() > tolerance * upper.abs().max(lower.abs()) {
+                return Err(LinalgError::NotSymmetric);
+            }
+        }
+    }
+    Ok(())
+}
+
+// Pivots this small, relative to the diagonal of A, are indistinguishable from
+// the cancellation you get when decomposing a singular matrix.
+fn pivot_tolerance<T>(a: &Matrix<T>) -> T
+where
+    T: Float,
+{
+    let max_diagonal = (0..a.rows).fold(T::zero(), |acc, i| acc.max(a[i][i].abs()));
+    max_diagonal * T::epsilon() * T::from(a.rows).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mat, row, traits::Transpose, vector::Row};
+
+    #[test]
+    fn test_llt_reconstructs_a() {
+        let a = mat![[25.0, 15.0, -5.0], [15.0, 18.0, 0.0], [-5.0, 0.0, 11.0]];
+        let chol = Cholesky::new(&a).unwrap();
+        assert_eq!(
+            &mat![[5.0, 0.0, 0.0], [3.0, 3.0, 0.0], [-1.0, 1.0, 3.0]],
+            chol.l()
+        );
+        assert_eq!(a, chol.l() * &chol.l().transpose());
+    }
+
+    #[test]
+    fn test_llt_and_ldlt_agree() {
+        let a = mat![[25.0, 15.0, -5.0], [15.0, 18.0, 0.0], [-5.0, 0.0, 11.0]];
+        let b = row![35.0, 33.0, 6.0];
+
+        let x = Cholesky::new(&a).unwrap().solve(&b);
+        let ldlt = Ldlt::new(&a).unwrap();
+        let y = ldlt.solve(&b);
+
+        assert!(ldlt.is_positive_definite());
+        for i in 0..3 {
+            assert!((x[i] - 1.0).abs() < 1e-12);
+            assert!((y[i] - 1.0).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_rejects_non_spd() {
+        let indefinite = mat![[1.0, 2.0], [2.0, 1.0]];
+        assert_eq!(
+            Err(LinalgError::NotPositiveDefinite { pivot: 1 }),
+            Cholesky::new(&indefinite)
+        );
+        assert!(!Ldlt::new(&indefinite).unwrap().is_positive_definite());
+
+        let rank_deficient = mat![[1.0, 2.0], [2.0, 4.0]];
+        assert_eq!(
+            Err(LinalgError::NotPositiveDefinite { pivot: 1 }),
+            Cholesky::new(&rank_deficient)
+        );
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 1 }),
+            Ldlt::new(&rank_deficient)
+        );
+    }
+
+    #[test]
+    fn test_rejects_bad_shape() {
+        assert_eq!(
+            Err(LinalgError::NotSymmetric),
+            Cholesky::new(&mat![[1.0, 2.0], [3.0, 1.0]])
+        );
+        assert_eq!(
+            Err(LinalgError::NotSquare { rows: 2, cols: 3 }),
+            Cholesky::new(&mat![[1.0, 2.0, 3.0], [2.0, 1.0, 3.0]])
+        );
+    }
+}
@@ -0,0 +1,701 @@
+use crate::{
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    solver::{cholesky, lu::LuFactorization},
+    vector::Vector,
+};
+use num_traits::{Float, NumAssignOps};
+use std::ops::Range;
+
+pub use num_complex::Complex;
+
+// A cyclic Jacobi sweep roughly squares the off-diagonal mass once it is small,
+// so this is only reached when something is badly wrong with the input.
+const MAX_JACOBI_SWEEPS: usize = 100;
+// QR steps allowed per eigenvalue, every 10th with an exceptional shift.
+const MAX_QR_ITERATIONS: usize = 30;
+
+/// An eigenvalue λ and unit eigenvector v such that Av = λv.
+#[derive(Debug, PartialEq, Clone)]
+pub struct EigenPair<T> {
+    pub value: T,
+    pub vector: Vector<T>,
+}
+
+/// Finds the eigenvalue of largest magnitude, and its eigenvector, by
+/// repeatedly multiplying a fixed starting vector by A.
+///
+/// Stops once the residual ‖Av - λv‖ is within `tol`·|λ|. Returns an error if A
+/// is not square or not finite, or convergence takes more than `max_iterations`,
+/// which happens when the two largest eigenvalues have the same magnitude.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, solver::eigen::power_iteration};
+///
+/// let a: Matrix<f64> = mat![[2.0, 1.0], [1.0, 2.0]];
+/// let pair = power_iteration(&a, 1e-12, 1000).unwrap();
+///
+/// assert!((pair.value - 3.0).abs() < 1e-10);
+/// ```
+pub fn power_iteration<T>(a: &Matrix<T>, tol: T, max_iterations: usize) -> Result<EigenPair<T>>
+where
+    T: Float + NumAssignOps,
+{
+    check_square(a)?;
+    a.check_finite()?;
+
+    let mut v = starting_vector(a.rows);
+    for iteration in 0..max_iterations {
+        let w = mul_vec(a, &v);
+        let value = dot(&v, &w);
+        if converged(&v, &w, value, tol) {
+            return Ok(EigenPair {
+                value,
+                vector: Vector::from(v),
+            });
+        }
+        v = normalized(w).ok_or(LinalgError::NoConvergence {
+            iterations: iteration,
+        })?;
+    }
+    Err(LinalgError::NoConvergence {
+        iterations: max_iterations,
+    })
+}
+
+/// Finds the eigenvalue of A closest to `shift`, and its eigenvector, by power
+/// iteration on (A - shift·I)⁻¹.
+///
+/// (A - shift·I) is factorized once and reused for every iteration. Returns an
+/// error under the same conditions as [`power_iteration`](fn.power_iteration.html).
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, solver::eigen::inverse_iteration};
+///
+/// let a: Matrix<f64> = mat![[2.0, 0.0, 0.0], [0.0, 5.0, 0.0], [0.0, 0.0, 9.0]];
+/// let pair = inverse_iteration(&a, 4.0, 1e-12, 1000).unwrap();
+///
+/// assert!((pair.value - 5.0).abs() < 1e-10);
+/// ```
+pub fn inverse_iteration<T>(
+    a: &Matrix<T>,
+    shift: T,
+    tol: T,
+    max_iterations: usize,
+) -> Result<EigenPair<T>>
+where
+    T: Float + NumAssignOps + num_traits::Signed,
+{
+    check_square(a)?;
+
+    let shifted = |shift: T| {
+        let mut shifted = a.clone();
+        for i in 0..a.rows {
+            shifted[i][i] -= shift;
+        }
+        LuFactorization::new(&shifted)
+    };
+    // A shift landing exactly on an eigenvalue is a perfectly good guess, so
+    // nudge it off rather than give up on the singular system.
+    let lu = match shifted(shift) {
+        Err(LinalgError::Singular { .. }) => {
+            shifted(shift + T::epsilon().sqrt() * (T::one() + shift.abs()))?
+        }
+        lu => lu?,
+    };
+
+    let mut v = starting_vector(a.rows);
+    for iteration in 0..max_iterations {
+        let w = mul_vec(a, &v);
+        let value = dot(&v, &w);
+        if converged(&v, &w, value, tol) {
+            return Ok(EigenPair {
+                value,
+                vector: Vector::from(v),
+            });
+        }
+        let next = lu.solve(&Vector::from(v));
+        v = normalized(next.iter().copied().collect()).ok_or(LinalgError::NoConvergence {
+            iterations: iteration,
+        })?;
+    }
+    Err(LinalgError::NoConvergence {
+        iterations: max_iterations,
+    })
+}
+
+/// The full eigendecomposition A = VΛVᵀ of a real symmetric `Matrix` A,
+/// computed by cyclic Jacobi rotations.
+///
+/// Eigenvalues are sorted from largest to smallest, and the eigenvectors are
+/// the matching columns of V, which is orthogonal.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SymmetricEigen<T> {
+    values: Vector<T>,
+    vectors: Matrix<T>,
+}
+
+impl<T> SymmetricEigen<T>
+where
+    T: Float + NumAssignOps,
+{
+    /// Decompose the symmetric `Matrix` `a`.
+    ///
+    /// Returns an error if `a` is not square and symmetric, or not finite.
+    /// Symmetry is judged to within rounding error, as by
+    /// [`cholesky`](../cholesky/index.html), and the two triangles are averaged
+    /// before decomposing.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix, solver::eigen::SymmetricEigen};
+    ///
+    /// let a: Matrix<f64> = mat![[2.0, 1.0], [1.0, 2.0]];
+    /// let eigen = SymmetricEigen::new(&a).unwrap();
+    ///
+    /// assert!((eigen.values()[0] - 3.0).abs() < 1e-12);
+    /// assert!((eigen.values()[1] - 1.0).abs() < 1e-12);
+    /// ```
+    pub fn new(a: &Matrix<T>) -> Result<Self> {
+        cholesky::check_symmetric(a)?;
+        let n = a.rows;
+
+        // Rotations keep any difference between the triangles, which would
+        // never sweep away, so start from the nearest symmetric matrix.
+        let two = T::one() + T::one();
+        let mut d = Matrix::from_fn(n, n, |i, j| (a[i][j] + a[j][i]) / two);
+        let mut v = Matrix::identity(n);
+
+        let norm = frobenius(&d);
+        let mut sweeps = 0;
+        while off_diagonal(&d) > T::epsilon() * norm {
+            sweeps += 1;
+            if sweeps > MAX_JACOBI_SWEEPS {
+                return Err(LinalgError::NoConvergence {
+                    iterations: MAX_JACOBI_SWEEPS,
+                });
+            }
+
+            for p in 0..n {
+                for q in p + 1..n {
+                    if d[p][q] == T::zero() {
+                        continue;
+                    }
+                    let theta = (d[q][q] - d[p][p]) / (d[p][q] + d[p][q]);
+                    let t = theta.signum() / (theta.abs() + (theta * theta + T::one()).sqrt());
+                    let c = T::one() / (t * t + T::one()).sqrt();
+                    let s = t * c;
+
+                    for k in 0..n {
+                        let (dkp, dkq) = (d[k][p], d[k][q]);
+                        d[k][p] = c * dkp - s * dkq;
+                        d[k][q] = s * dkp + c * dkq;
+                    }
+                    for k in 0..n {
+                        let (dpk, dqk) = (d[p][k], d[q][k]);
+                        d[p][k] = c * dpk - s * dqk;
+                        d[q][k] = s * dpk + c * dqk;
+                    }
+                    for k in 0..n {
+                        let (vkp, vkq) = (v[k][p], v[k][q]);
+                        v[k][p] = c * vkp - s * vkq;
+                        v[k][q] = s * vkp + c * vkq;
+                    }
+                }
+            }
+        }
+
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|x, y| d[*y][*y].partial_cmp(&d[*x][*x]).unwrap());
+
+        let mut vectors = Matrix::new(n, n);
+        for (k, j) in order.iter().enumerate() {
+            for i in 0..n {
+                vectors[i][k] = v[i][*j];
+            }
+        }
+
+        Ok(SymmetricEigen {
+            values: Vector::from(order.iter().map(|j| d[*j][*j]).collect::<Vec<T>>()),
+            vectors,
+        })
+    }
+
+    /// The eigenvalues, largest first.
+    pub fn values(&self) -> &Vector<T> {
+        &self.values
+    }
+
+    /// The unit eigenvectors, as the columns of an orthogonal `Matrix`.
+    pub fn vectors(&self) -> &Matrix<T> {
+        &self.vectors
+    }
+
+    /// The `k`-th eigenpair, counting from the largest eigenvalue.
+    pub fn pair(&self, k: usize) -> EigenPair<T> {
+        EigenPair {
+            value: self.values[k],
+            vector: self.vectors.col(k),
+        }
+    }
+}
+
+/// Reduces a square `Matrix` to upper Hessenberg form, zero below its first
+/// subdiagonal, by Householder similarity transforms. The result has the same
+/// eigenvalues as `a`.
+///
+/// Returns an error if `a` is not square, or not finite.
+pub fn hessenberg<T>(a: &Matrix<T>) -> Result<Matrix<T>>
+where
+    T: Float + NumAssignOps,
+{
+    check_square(a)?;
+    a.check_finite()?;
+
+    let n = a.rows;
+    let mut h = a.clone();
+
+    for k in 0..n.saturating_sub(2) {
+        let x: Vec<T> = (k + 1..n).map(|i| h[i][k]).collect();
+        if let Some(v) = householder(&x) {
+            reflect_rows(&mut h, &v, k + 1, 0..n);
+            reflect_cols(&mut h, &v, k + 1, 0..n);
+            for i in k + 2..n {
+                h[i][k] = T::zero();
+            }
+        }
+    }
+
+    Ok(h)
+}
+
+/// Finds every eigenvalue of a real square `Matrix`, by reducing it to
+/// Hessenberg form and running the shifted QR algorithm with Francis double
+/// steps, so complex conjugate pairs never need complex arithmetic.
+///
+/// Eigenvalues are sorted by decreasing real part, and within a conjugate pair
+/// the one with positive imaginary part comes first. Returns an error if `a` is
+/// not square or not finite, or the iteration fails to converge.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, solver::eigen::{eigenvalues, Complex}};
+///
+/// // Rotation by 90°
+/// let a: Matrix<f64> = mat![[0.0, -1.0], [1.0, 0.0]];
+/// let values = eigenvalues(&a).unwrap();
+///
+/// assert_eq!(vec![Complex::new(0.0, 1.0), Complex::new(0.0, -1.0)], values);
+/// ```
+pub fn eigenvalues<T>(a: &Matrix<T>) -> Result<Vec<Complex<T>>>
+where
+    T: Float + NumAssignOps,
+{
+    let mut h = hessenberg(a)?;
+    let n = h.rows;
+    // Similarity transforms preserve the Frobenius norm, so this scales the
+    // deflation test wherever the diagonal nearby is zero.
+    let norm = frobenius(&h);
+
+    let mut values = Vec::with_capacity(n);
+    // Eigenvalues are deflated off the bottom right, leaving rows and columns
+    // 0..end still to solve.
+    let mut end = n;
+    let mut iterations = 0;
+    while end > 0 {
+        // The unreduced block start..end above the last negligible subdiagonal.
+        let mut start = end - 1;
+        while start > 0 {
+            let mut scale = h[start - 1][start - 1].abs() + h[start][start].abs();
+            if scale == T::zero() {
+                scale = norm;
+            }
+            if h[start][start - 1].abs() <= T::epsilon() * scale {
+                h[start][start - 1] = T::zero();
+                break;
+            }
+            start -= 1;
+        }
+
+        match end - start {
+            1 => {
+                values.push(Complex::new(h[start][start], T::zero()));
+                end -= 1;
+                iterations = 0;
+                continue;
+            }
+            2 => {
+                let (first, second) = eigenvalues_2x2(
+                    h[start][start],
+                    h[start][start + 1],
+                    h[start + 1][start],
+                    h[start + 1][start + 1],
+                );
+                values.push(first);
+                values.push(second);
+                end -= 2;
+                iterations = 0;
+                continue;
+            }
+            _ => (),
+        }
+
+        if iterations == MAX_QR_ITERATIONS {
+            return Err(LinalgError::NoConvergence {
+                iterations: MAX_QR_ITERATIONS,
+            });
+        }
+        iterations += 1;
+
+        // Shift by both eigenvalues σ₁, σ₂ of the trailing 2 x 2 block at once,
+        // given by their sum σ₁ + σ₂ and product σ₁σ₂, which are real even when
+        // the shifts are a complex conjugate pair.
+        let (p, q) = (end - 2, end - 1);
+        let (sum, product) = if iterations % 10 == 0 {
+            // Those shifts can fall into a cycle which never converges, as for
+            // a permutation matrix, on which a QR step only permutes H. Moving
+            // a double real shift off the last diagonal entry by the size of
+            // the subdiagonal entries which won't shrink breaks the cycle.
+            let sigma = h[q][q] + h[q][p].abs() + h[p][p - 1].abs();
+            (sigma + sigma, sigma * sigma)
+        } else {
+            (h[p][p] + h[q][q], h[p][p] * h[q][q] - h[p][q] * h[q][p])
+        };
+        francis_step(&mut h, start, end, sum, product);
+    }
+
+    values.sort_by(|x, y| {
+        y.re.partial_cmp(&x.re)
+            .unwrap()
+            .then(y.im.partial_cmp(&x.im).unwrap())
+    });
+    Ok(values)
+}
+
+// One implicit double-shift QR step on the unreduced Hessenberg block
+// start..end, of at least 3 x 3, with shifts σ₁ + σ₂ = `sum` and σ₁σ₂ =
+// `product`. A reflector mapping the first column of (H - σ₁I)(H - σ₂I) onto
+// e₀ creates a bulge below the subdiagonal, which further reflectors chase off
+// the bottom, returning H to Hessenberg form.
+fn francis_step<T>(h: &mut Matrix<T>, start: usize, end: usize, sum: T, product: T)
+where
+    T: Float + NumAssignOps,
+{
+    let s = start;
+    // H² - (σ₁ + σ₂)H + σ₁σ₂I has only three non-zero entries in its first
+    // column, as H is Hessenberg.
+    let mut bulge = vec![
+        h[s][s] * h[s][s] + h[s][s + 1] * h[s + 1][s] - sum * h[s][s] + product,
+        h[s + 1][s] * (h[s][s] + h[s + 1][s + 1] - sum),
+        h[s + 1][s] * h[s + 2][s + 1],
+    ];
+
+    for k in start..end - 1 {
+        if let Some(v) = householder(&bulge) {
+            // The first reflector acts on the block's first column, each later
+            // one on the column holding the bulge, left of its rows.
+            let first_col = if k > start { k - 1 } else { start };
+            reflect_rows(h, &v, k, first_col..end);
+            reflect_cols(h, &v, k, start..(k + 4).min(end));
+            if k > start {
+                for i in k + 1..k + v.len() {
+                    h[i][k - 1] = T::zero();
+                }
+            }
+        }
+        // The bulge has moved down a column, and shrinks to two entries at the
+        // bottom of the block.
+        bulge = (k + 1..(k + 4).min(end)).map(|i| h[i][k]).collect();
+    }
+}
+
+// The eigenvalues of [[a, b], [c, d]], the roots of λ² - (a + d)λ + (ad - bc),
+// a complex conjugate pair or two reals.
+fn eigenvalues_2x2<T: Float>(a: T, b: T, c: T, d: T) -> (Complex<T>, Complex<T>) {
+    let half = T::from(0.5).unwrap();
+    let mean = half * (a + d);
+    let gap = half * (a - d);
+    let discriminant = gap * gap + b * c;
+    let root = discriminant.abs().sqrt();
+    if discriminant < T::zero() {
+        return (Complex::new(mean, root), Complex::new(mean, -root));
+    }
+
+    // Adding root to mean with the sign they share avoids cancellation; the
+    // other eigenvalue then follows from their product, the determinant.
+    let larger = if mean < T::zero() {
+        mean - root
+    } else {
+        mean + root
+    };
+    let smaller = if larger == T::zero() {
+        T::zero()
+    } else {
+        (a * d - b * c) / larger
+    };
+    (
+        Complex::new(larger, T::zero()),
+        Complex::new(smaller, T::zero()),
+    )
+}
+
+// The unit vector v for which (I - 2vvᵀ)x is a multiple of e₀, or None if x is
+// zero.
+fn householder<T: Float>(x: &[T]) -> Option<Vec<T>> {
+    let norm_x = norm(x);
+    // Reflect onto -sign(x₀)‖x‖e₀ to avoid cancellation in v₀.
+    let alpha = if x[0] < T::zero() { norm_x } else { -norm_x };
+    let mut v = x.to_vec();
+    v[0] = v[0] - alpha;
+    normalized(v)
+}
+
+// Applies I - 2vvᵀ from the left to rows `first_row..` and columns `cols` of `h`.
+fn reflect_rows<T>(h: &mut Matrix<T>, v: &[T], first_row: usize, cols: Range<usize>)
+where
+    T: Float + NumAssignOps,
+{
+    let two = T::one() + T::one();
+    for j in cols {
+        let dot = v
+            .iter()
+            .enumerate()
+            .fold(T::zero(), |acc, (i, vi)| acc + *vi * h[first_row + i][j]);
+        for (i, vi) in v.iter().enumerate() {
+            h[first_row + i][j] -= two * *vi * dot;
+        }
+    }
+}
+
+// Applies I - 2vvᵀ from the right to columns `first_col..` and rows `rows` of `h`.
+fn reflect_cols<T>(h: &mut Matrix<T>, v: &[T], first_col: usize, rows: Range<usize>)
+where
+    T: Float + NumAssignOps,
+{
+    let two = T::one() + T::one();
+    for i in rows {
+        let dot = v
+            .iter()
+            .enumerate()
+            .fold(T::zero(), |acc, (j, vj)| acc + h[i][first_col + j] * *vj);
+        for (j, vj) in v.iter().enumerate() {
+            h[i][first_col + j] -= two * dot * *vj;
+        }
+    }
+}
+
+fn check_square<T>(a: &Matrix<T>) -> Result<()> {
+    if a.rows != a.cols {
+        return Err(LinalgError::NotSquare {
+            rows: a.rows,
+            cols: a.cols,
+        });
+    }
+    Ok(())
+}
+
+fn mul_vec<T>(a: &Matrix<T>, v: &[T]) -> Vec<T>
+where
+    T: Float,
+{
+    a.iter().map(|row| dot(row, v)).collect()
+}
+
+fn dot<T: Float>(a: &[T], b: &[T]) -> T {
+    a.iter()
+        .zip(b.iter())
+        .fold(T::zero(), |acc, (x, y)| acc + *x * *y)
+}
+
+fn norm<T: Float>(v: &[T]) -> T {
+    v.iter().fold(T::zero(), |acc, x| acc.hypot(*x))
+}
+
+// Entries 1, 1/2, 1/3, ... rather than all ones, which is an eigenvector of
+// every matrix with constant row sums.
+fn starting_vector<T: Float>(n: usize) -> Vec<T> {
+    let v: Vec<T> = (1..=n).map(|i| T::one() / T::from(i).unwrap()).collect();
+    normalized(v).unwrap()
+}
+
+fn normalized<T: Float>(v: Vec<T>) -> Option<Vec<T>> {
+    let norm = norm(&v);
+    if norm == T::zero() || !norm.is_finite() {
+        return None;
+    }
+    Some(v.into_iter().map(|x| x / norm).collect())
+}
+
+fn converged<T: Float>(v: &[T], av: &[T], value: T, tol: T) -> bool {
+    let residual = v
+        .iter()
+        .zip(av.iter())
+        .fold(T::zero(), |acc, (x, y)| acc.hypot(*y - value * *x));
+    residual <= tol * value.abs().max(T::min_positive_value())
+}
+
+fn frobenius<T: Float>(a: &Matrix<T>) -> T {
+    a.iter()
+        .flat_map(|row| row.iter())
+        .fold(T::zero(), |acc, x| acc.hypot(*x))
+}
+
+fn off_diagonal<T: Float>(a: &Matrix<T>) -> T {
+    a.iter().enumerate().fold(T::zero(), |acc, (i, row)| {
+        row.iter()
+            .enumerate()
+            .filter(|(j, _)| *j != i)
+            .fold(acc, |acc, (_, x)| acc.hypot(*x))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mat, test_util::assert_close, traits::Transpose};
+
+    #[test]
+    fn test_power_iteration_negative_dominant() {
+        let a = mat![[-4.0, 1.0], [1.0, 2.0]];
+        let pair = power_iteration(&a, 1e-12, 10_000).unwrap();
+        assert_close(-1.0 - 10f64.sqrt(), pair.value);
+    }
+
+    #[test]
+    fn test_power_iteration_rejects_non_square() {
+        assert_eq!(
+            Err(LinalgError::NotSquare { rows: 1, cols: 2 }),
+            power_iteration(&mat![[1.0, 2.0]], 1e-12, 10)
+        );
+    }
+
+    #[test]
+    fn test_inverse_iteration_exact_shift() {
+        let a = mat![[2.0, 1.0], [1.0, 2.0]];
+        let pair = inverse_iteration(&a, 1.0, 1e-12, 100).unwrap();
+        assert_close(1.0, pair.value);
+        assert_close(pair.vector[0].abs(), pair.vector[1].abs());
+    }
+
+    #[test]
+    fn test_symmetric_eigen_reconstructs_a() {
+        let a = mat![[4.0, 1.0, 2.0], [1.0, 3.0, 0.0], [2.0, 0.0, 1.0]];
+        let eigen = SymmetricEigen::new(&a).unwrap();
+        let v = eigen.vectors();
+
+        let mut v_lambda = v.clone();
+        for i in 0..3 {
+            for k in 0..3 {
+                v_lambda[i][k] *= eigen.values()[k];
+            }
+        }
+        let reconstructed = &v_lambda * &v.transpose();
+        for i in 0..3 {
+            for j in 0..3 {
+                assert_close(a[i][j], reconstructed[i][j]);
+            }
+        }
+        assert!(eigen.values()[0] >= eigen.values()[1]);
+        assert!(eigen.values()[1] >= eigen.values()[2]);
+    }
+
+    #[test]
+    fn test_symmetric_eigen_rejects_asymmetric() {
+        assert_eq!(
+            Err(LinalgError::NotSymmetric),
+            SymmetricEigen::new(&mat![[1.0, 2.0], [3.0, 4.0]])
+        );
+    }
+
+    #[test]
+    fn test_symmetric_eigen_accepts_rounding_asymmetry() {
+        // Symmetric but for the last bit of one entry, as AᵀA often comes out.
+        let a = mat![[2.0, 1.0 + f64::EPSILON], [1.0, 2.0]];
+        let eigen = SymmetricEigen::new(&a).unwrap();
+        assert_close(3.0, eigen.values()[0]);
+        assert_close(1.0, eigen.values()[1]);
+    }
+
+    #[test]
+    fn test_hessenberg_form() {
+        let a = mat![
+            [4.0, 1.0, -2.0, 2.0],
+            [1.0, 2.0, 0.0, 1.0],
+            [-2.0, 0.0, 3.0, -2.0],
+            [2.0, 1.0, -2.0, -1.0]
+        ];
+        let h = hessenberg(&a).unwrap();
+        assert_eq!(0.0, h[2][0]);
+        assert_eq!(0.0, h[3][0]);
+        assert_eq!(0.0, h[3][1]);
+        // Similarity transforms preserve the trace.
+        assert_close(8.0, h[0][0] + h[1][1] + h[2][2] + h[3][3]);
+    }
+
+    #[test]
+    fn test_general_eigenvalues() {
+        // Companion matrix of (x - 1)(x - 2)(x² + 1) = x⁴ - 3x³ + 3x² - 3x + 2
+        let a = mat![
+            [3.0, -3.0, 3.0, -2.0],
+            [1.0, 0.0, 0.0, 0.0],
+            [0.0, 1.0, 0.0, 0.0],
+            [0.0, 0.0, 1.0, 0.0]
+        ];
+        let values = eigenvalues(&a).unwrap();
+        let expected = [(2.0, 0.0), (1.0, 0.0), (0.0, 1.0), (0.0, -1.0)];
+        assert_eq!(4, values.len());
+        for (value, (re, im)) in values.iter().zip(expected.iter()) {
+            assert_close(*re, value.re);
+            assert_close(*im, value.im);
+        }
+    }
+
+    #[test]
+    fn test_eigenvalues_of_cyclic_permutation() {
+        // Every eigenvalue is a 5th root of unity, all the same modulus, which
+        // stalls the standard shifts until an exceptional one.
+        let n = 5;
+        let a = Matrix::from_fn(n, n, |i, j| if (i + 1) % n == j { 1.0 } else { 0.0 });
+        let values = eigenvalues(&a).unwrap();
+        assert_eq!(n, values.len());
+        for value in values.iter() {
+            let power = value.powi(n as i32);
+            assert_close(1.0, power.re);
+            assert_close(0.0, power.im);
+        }
+        assert_close(1.0, values[0].re);
+    }
+
+    #[test]
+    fn test_eigenvalues_preserve_trace_and_determinant() {
+        let n = 8;
+        let a = Matrix::<f64>::random(n, n, 17);
+        let values = eigenvalues(&a).unwrap();
+
+        let sum = values.iter().fold(Complex::new(0.0, 0.0), |acc, x| acc + x);
+        let product = values.iter().fold(Complex::new(1.0, 0.0), |acc, x| acc * x);
+        assert_close((0..n).map(|i| a[i][i]).sum(), sum.re);
+        assert_close(0.0, sum.im);
+        assert_close(a.determinant().unwrap(), product.re);
+        assert_close(0.0, product.im);
+        for pair in values.windows(2) {
+            assert!(pair[0].re >= pair[1].re);
+        }
+    }
+
+    #[test]
+    fn test_eigenvalues_of_triangular_and_small() {
+        let a = mat![[1.0, 5.0, -2.0], [0.0, -3.0, 4.0], [0.0, 0.0, 2.0]];
+        let expected = [2.0, 1.0, -3.0];
+        for (value, re) in eigenvalues(&a).unwrap().iter().zip(expected.iter()) {
+            assert_close(*re, value.re);
+            assert_eq!(0.0, value.im);
+        }
+        assert_eq!(
+            vec![Complex::new(7.0, 0.0)],
+            eigenvalues(&mat![[7.0]]).unwrap()
+        );
+    }
+}
@@ -1,56 +1,458 @@
-use crate::{matrix::Matrix, traits::PositionalMax, vector::Vector};
-use num_traits::Num;
+use crate::{
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    solver::trace::{Step, Trace},
+    traits::Augment,
+    vector::Vector,
+};
+use num_integer::Integer;
+use num_rational::Ratio;
+use num_traits::{Float, Num, Signed};
 
+/// Solves the system described by the augmented `Matrix` [A | b] by gaussian
+/// elimination, returning x such that Ax = b.
+///
+/// Each pivot row is divided through by its pivot, which truncates integer
+/// entries. Solve integer systems with [`bareiss::solve`](../bareiss/fn.solve.html),
+/// or with `Ratio` entries. To know how far to trust a floating point solution,
+/// or to refine it, use [`refinement::solve`](../refinement/fn.solve.html).
+///
+/// # Panics
+/// Panics if `a` is not an augmented square matrix, or the system is singular.
+/// See [`try_solve`](fn.try_solve.html) for a fallible version.
 pub fn solve<T>(a: Matrix<T>) -> Vector<T>
 where
-    T: PartialOrd + Num + Copy + num_traits::NumAssignOps + std::fmt::Debug,
+    T: PartialOrd
+        + Num
+        + Signed
+        + Copy
+        + num_traits::NumAssignOps
+        + std::fmt::Debug
+        + PivotTolerance,
 {
-    assert_eq!(
-        a.rows,
-        a.cols - 1,
-        "Gaussian elimination requires an augmented square matrix"
-    );
+    match try_solve(a) {
+        Ok(x) => x,
+        Err(e) => panic!("Gaussian elimination failed: {}", e),
+    }
+}
+
+/// Fallible version of [`solve`](fn.solve.html), pivoting on the entry of
+/// largest magnitude in each column.
+///
+/// Returns an error if `a` is not an augmented square matrix, contains a NaN or
+/// infinite entry, or a pivot which is zero, to within its
+/// [`PivotTolerance`](trait.PivotTolerance.html), is met during elimination.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, error::LinalgError, matrix::Matrix, solver::gauss};
+///
+/// let singular = mat![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]];
+/// assert_eq!(Err(LinalgError::Singular { pivot: 1 }), gauss::try_solve(singular));
+/// ```
+pub fn try_solve<T>(a: Matrix<T>) -> Result<Vector<T>>
+where
+    T: PartialOrd
+        + Num
+        + Signed
+        + Copy
+        + num_traits::NumAssignOps
+        + std::fmt::Debug
+        + PivotTolerance,
+{
+    solve_with(a, PivotStrategy::Partial).map(|s| s.solution)
+}
 
-    let mut a_prime = a.clone();
+/// How gaussian elimination chooses the pivot for each column.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum PivotStrategy {
+    /// Take the diagonal entry as it stands. Fails on a zero pivot, and loses
+    /// accuracy to a small one.
+    None,
+    /// Swap up the row whose entry in the column has the largest magnitude.
+    #[default]
+    Partial,
+    /// Swap up the row whose entry in the column is largest relative to the
+    /// largest magnitude in that row of A, which is unaffected by scaling
+    /// the equations.
+    ScaledPartial,
+    /// Swap up the row and column holding the largest magnitude remaining in
+    /// A, reordering the unknowns. The most stable, at O(n³) comparisons.
+    Complete,
+}
 
-    for i in 0..a_prime.rows {
+/// The solution of a system by [`solve_with`](fn.solve_with.html), and the
+/// pivots elimination took to reach it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct PivotedSolution<T> {
+    /// x such that Ax = b, in the original order of the unknowns.
+    pub solution: Vector<T>,
+    /// `row_permutation[k]` is the row of A used as the k-th pivot row.
+    pub row_permutation: Vec<usize>,
+    /// `col_permutation[k]` is the column of A, and so the unknown, eliminated
+    /// k-th. The identity unless pivoting is `Complete`.
+    pub col_permutation: Vec<usize>,
+}
+
+/// Solves the system described by the augmented `Matrix` [A | b] by gaussian
+/// elimination, choosing pivots by `strategy`.
+///
+/// Returns an error if `a` is not an augmented square matrix, contains a NaN or
+/// infinite entry, or a pivot which is zero, to within its
+/// [`PivotTolerance`](trait.PivotTolerance.html), is met during elimination.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, solver::gauss::{self, PivotStrategy}};
+///
+/// // The equations are badly scaled: partial pivoting keeps the first row,
+/// // while scaled partial pivoting sees the second is relatively larger.
+/// let a = mat![[2.0, 100000.0, 100000.0], [1.0, 1.0, 2.0]];
+///
+/// let partial = gauss::solve_with(a.clone(), PivotStrategy::Partial).unwrap();
+/// assert_eq!(vec![0, 1], partial.row_permutation);
+///
+/// let scaled = gauss::solve_with(a, PivotStrategy::ScaledPartial).unwrap();
+/// assert_eq!(vec![1, 0], scaled.row_permutation);
+/// ```
+pub fn solve_with<T>(a: Matrix<T>, strategy: PivotStrategy) -> Result<PivotedSolution<T>>
+where
+    T: PartialOrd
+        + Num
+        + Signed
+        + Copy
+        + num_traits::NumAssignOps
+        + std::fmt::Debug
+        + PivotTolerance,
+{
+    pivoted_solve(a, strategy, &mut |_, _| {})
+}
+
+/// [`solve_with`](fn.solve_with.html), also recording every row swap,
+/// scaling and elimination, with the matrix after each, as a `Trace`.
+///
+/// The trace is returned even when elimination fails, ending at the step
+/// before the zero pivot was met.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, solver::{gauss::{self, PivotStrategy}, trace::Step}};
+///
+/// let (solution, trace) = gauss::solve_with_trace(mat![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]], PivotStrategy::Partial);
+///
+/// assert!(solution.is_err());
+/// assert_eq!(Step::SwapRows { a: 0, b: 1 }, trace.steps[0].step);
+/// // The last step leaves a zero where the second pivot should be.
+/// assert!(trace.to_text().ends_with("R2 ← R2 − (1)R1\n[ 1  2 | 3 ]\n[ 0  0 | 0 ]\n"));
+/// ```
+pub fn solve_with_trace<T>(
+    a: Matrix<T>,
+    strategy: PivotStrategy,
+) -> (Result<PivotedSolution<T>>, Trace<T>)
+where
+    T: PartialOrd
+        + Num
+        + Signed
+        + Copy
+        + num_traits::NumAssignOps
+        + std::fmt::Debug
+        + PivotTolerance,
+{
+    let mut trace = Trace::new(a.clone());
+    let solution = pivoted_solve(a, strategy, &mut |step, m| trace.record(step, m));
+    (solution, trace)
+}
+
+// Elimination for `solve_with`, calling `record` after each operation.
+fn pivoted_solve<T>(
+    a: Matrix<T>,
+    strategy: PivotStrategy,
+    record: &mut dyn FnMut(Step<T>, &Matrix<T>),
+) -> Result<PivotedSolution<T>>
+where
+    T: PartialOrd
+        + Num
+        + Signed
+        + Copy
+        + num_traits::NumAssignOps
+        + std::fmt::Debug
+        + PivotTolerance,
+{
+    if a.rows + 1 != a.cols {
+        return Err(LinalgError::DimensionMismatch {
+            expected: (a.rows, a.rows + 1),
+            found: (a.rows, a.cols),
+        });
+    }
+    a.check_finite()?;
+
+    let n = a.rows;
+    let mut a_prime = a;
+    let mut row_permutation = (0..n).collect::<Vec<_>>();
+    let mut col_permutation = (0..n).collect::<Vec<_>>();
+    // Largest magnitude in each row of A, for scaled partial pivoting.
+    let mut scales = a_prime
+        .rows()
+        .map(|row| {
+            row[..n]
+                .iter()
+                .fold(T::zero(), |acc, x| larger(acc, x.abs()))
+        })
+        .collect::<Vec<_>>();
+
+    let max_abs = a_prime
+        .rows()
+        .flat_map(|row| row[..n].iter())
+        .fold(T::zero(), |acc, x| larger(acc, x.abs()));
+    let tolerance = T::pivot_tolerance(n, max_abs);
+
+    for i in 0..n {
         // Swap
-        let first_col_under_i = &a_prime[..][i][i..];
-        if let Some(swap_value) = first_col_under_i.max_at() {
-            a_prime = a_prime.swap_rows(i, swap_value.0 + i);
+        let (pivot_row, pivot_col) = match strategy {
+            PivotStrategy::None => (i, i),
+            PivotStrategy::Partial => (argmax(i..n, |r| a_prime[r][i].abs()), i),
+            PivotStrategy::ScaledPartial => (
+                argmax(i..n, |r| {
+                    if scales[r].is_zero() {
+                        T::zero()
+                    } else {
+                        a_prime[r][i].abs() / scales[r]
+                    }
+                }),
+                i,
+            ),
+            PivotStrategy::Complete => {
+                let remaining = (i..n).flat_map(|r| (i..n).map(move |c| (r, c)));
+                argmax(remaining, |(r, c)| a_prime[r][c].abs())
+            }
+        };
+        if pivot_row != i {
+            a_prime.swap_rows_in_place(i, pivot_row);
+            row_permutation.swap(i, pivot_row);
+            scales.swap(i, pivot_row);
+            record(Step::SwapRows { a: i, b: pivot_row }, &a_prime);
+        }
+        if pivot_col != i {
+            a_prime.rows_mut().for_each(|row| row.swap(i, pivot_col));
+            col_permutation.swap(i, pivot_col);
+            record(Step::SwapCols { a: i, b: pivot_col }, &a_prime);
         }
 
         // Scale
         let first_entry = a_prime[i][i];
-        // data_cols goes out of sync here. This whole she-bang needs rewritten to only
-        // talk to a_prime, not with IndexMut which clearly needs unimplemented.
-        a_prime[i] /= first_entry;
+        if first_entry.abs() <= tolerance {
+            return Err(LinalgError::Singular { pivot: i });
+        }
+        if !first_entry.is_one() {
+            a_prime[i].iter_mut().for_each(|x| *x /= first_entry);
+            record(
+                Step::Scale {
+                    row: i,
+                    divisor: first_entry,
+                },
+                &a_prime,
+            );
+        }
 
         // Eliminate
-        eliminate(&mut a_prime, i);
+        eliminate(&mut a_prime, i, record);
     }
 
     // Backsolve
-    backsolve(&mut a_prime);
+    backsolve(&mut a_prime, record);
 
-    a_prime.sync();
-    a_prime[..][a.cols - 1].clone()
+    // The k-th entry of the last column is the unknown eliminated k-th.
+    let y = a_prime.col(n);
+    let mut solution = y.clone();
+    for (k, &unknown) in col_permutation.iter().enumerate() {
+        solution[unknown] = y[k];
+    }
+
+    Ok(PivotedSolution {
+        solution,
+        row_permutation,
+        col_permutation,
+    })
 }
 
-fn eliminate<T>(a: &mut Matrix<T>, basis_row_idx: usize)
+/// Computes A⁻¹ by Gauss–Jordan elimination on [A | I], with partial pivoting.
+///
+/// The inverse of an integer matrix is generally not an integer matrix, so
+/// this is only for floating point entries.
+///
+/// Returns an error if `a` is not square, contains a NaN or infinite entry, or
+/// a pivot within rounding error of zero is met, in which case A is singular.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, solver::gauss};
+///
+/// let a = mat![[2.0, 0.0], [1.0, 4.0]];
+/// assert_eq!(Ok(mat![[0.5, 0.0], [-0.125, 0.25]]), gauss::inverse(&a));
+/// ```
+pub fn inverse<T>(a: &Matrix<T>) -> Result<Matrix<T>>
 where
+    T: Float + num_traits::NumAssignOps,
+{
+    if a.rows != a.cols {
+        return Err(LinalgError::NotSquare {
+            rows: a.rows,
+            cols: a.cols,
+        });
+    }
+    a.check_finite()?;
+
+    let n = a.rows;
+    let max_abs = a
+        .iter()
+        .flatten()
+        .fold(T::zero(), |acc, x| acc.max(x.abs()));
+    let tolerance = float_pivot_tolerance(n, max_abs);
+    let mut a_prime = a.augment(&Matrix::identity(n));
+
+    for i in 0..n {
+        let pivot_row = (i..n)
+            .max_by(|x, y| {
+                a_prime[*x][i]
+                    .abs()
+                    .partial_cmp(&a_prime[*y][i].abs())
+                    .expect("matrix entries must be ordered")
+            })
+            .unwrap();
+        if a_prime[pivot_row][i].abs() <= tolerance {
+            return Err(LinalgError::Singular { pivot: i });
+        }
+        a_prime.swap_rows_in_place(i, pivot_row);
+
+        let pivot = a_prime[i][i];
+        a_prime[i].iter_mut().for_each(|x| *x /= pivot);
+
+        // Unlike plain elimination, clear the column above the pivot as well.
+        for k in (0..n).filter(|k| *k != i) {
+            let s = a_prime[k][i];
+            if s.is_zero() {
+                continue;
+            }
+            for j in i..2 * n {
+                let scaled = a_prime[i][j] * s;
+                a_prime[k][j] -= scaled;
+            }
+        }
+    }
+
+    let mut inverse = Matrix::new(n, n);
+    for i in 0..n {
+        inverse[i].copy_from_slice(&a_prime[i][n..]);
+    }
+    Ok(inverse)
+}
+
+/// How small a pivot must be for gaussian elimination to treat it as zero.
+///
+/// A floating point pivot of an n x n matrix A with |p| ≤ nε·max|aᵢⱼ| is within
+/// rounding error of zero, as the pivots of a singular matrix end up, so it
+/// is reported as `LinalgError::Singular` rather than divided by. Exact
+/// entries, integers and `Ratio`, only treat zero as zero.
+pub trait PivotTolerance: Sized {
+    /// The tolerance for an n x n matrix whose largest magnitude is `max_abs`.
+    fn pivot_tolerance(n: usize, max_abs: Self) -> Self;
+}
+
+macro_rules! impl_float_pivot_tolerance {
+    ($($t:ty),*) => {$(
+        impl PivotTolerance for $t {
+            fn pivot_tolerance(n: usize, max_abs: Self) -> Self {
+                float_pivot_tolerance(n, max_abs)
+            }
+        }
+    )*};
+}
+
+macro_rules! impl_exact_pivot_tolerance {
+    ($($t:ty),*) => {$(
+        impl PivotTolerance for $t {
+            fn pivot_tolerance(_: usize, _: Self) -> Self {
+                0
+            }
+        }
+    )*};
+}
+
+impl_float_pivot_tolerance!(f32, f64);
+impl_exact_pivot_tolerance!(i8, i16, i32, i64, i128, isize);
+
+impl<T> PivotTolerance for Ratio<T>
+where
+    T: Clone + Integer,
+{
+    fn pivot_tolerance(_: usize, _: Self) -> Self {
+        Ratio::from_integer(T::zero())
+    }
+}
+
+// nε·max|aᵢⱼ|, the `PivotTolerance` of floating point elimination.
+pub(crate) fn float_pivot_tolerance<T: Float>(n: usize, max_abs: T) -> T {
+    max_abs * T::epsilon() * T::from(n).unwrap()
+}
+
+// The first candidate whose key is largest.
+fn argmax<I, K, T>(candidates: I, mut key: K) -> I::Item
+where
+    I: Iterator,
+    I::Item: Copy,
+    K: FnMut(I::Item) -> T,
+    T: PartialOrd,
+{
+    let mut candidates = candidates;
+    let first = candidates.next().expect("no candidate pivots");
+    let mut best = (first, key(first));
+    for candidate in candidates {
+        let k = key(candidate);
+        if k > best.1 {
+            best = (candidate, k);
+        }
+    }
+    best.0
+}
+
+fn larger<T: PartialOrd>(a: T, b: T) -> T {
+    if b > a {
+        b
+    } else {
+        a
+    }
+}
+
+fn eliminate<T>(
+    a: &mut Matrix<T>,
+    basis_row_idx: usize,
+    record: &mut dyn FnMut(Step<T>, &Matrix<T>),
+) where
     T: PartialOrd + Num + Copy + num_traits::NumAssignOps,
 {
     let start_col = basis_row_idx;
 
     for i in basis_row_idx + 1..a.rows {
         let s = a[i][start_col];
-        let scaled = &a[basis_row_idx] * s;
-        a[i] = &a[i] - &scaled;
+        if s.is_zero() {
+            continue;
+        }
+        for j in 0..a.cols {
+            let scaled = a[basis_row_idx][j] * s;
+            a[i][j] -= scaled;
+        }
+        record(
+            Step::Eliminate {
+                row: i,
+                pivot_row: basis_row_idx,
+                multiplier: s,
+            },
+            a,
+        );
     }
 }
 
-fn backsolve<T>(a: &mut Matrix<T>)
+fn backsolve<T>(a: &mut Matrix<T>, record: &mut dyn FnMut(Step<T>, &Matrix<T>))
 where
     T: PartialOrd + Num + Copy + num_traits::NumAssignOps,
 {
@@ -59,10 +461,21 @@ where
     for i in (1..augment_i).rev() {
         for j in (0..i).rev() {
             let s = a[j][i];
+            if s.is_zero() {
+                continue;
+            }
             let entry = s * a[i][i];
             a[j][i] -= entry;
             let entry = s * a[i][augment_i];
             a[j][augment_i] -= entry;
+            record(
+                Step::BackSubstitute {
+                    row: j,
+                    pivot_row: i,
+                    multiplier: s,
+                },
+                a,
+            );
         }
     }
 }
@@ -70,7 +483,133 @@ where
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::mat;
+    use crate::{mat, row, vector::Row};
+
+    #[test]
+    fn test_solve() {
+        let a = mat![[2.0, 1.0, 5.0], [1.0, 3.0, 10.0]];
+        assert_eq!(row![1.0, 3.0], solve(a));
+    }
+
+    #[test]
+    fn test_partial_pivoting_uses_magnitude() {
+        // The largest signed entry of the first column is 1, but -4 is the
+        // better pivot.
+        let a = mat![[1.0, 3.0, 5.0], [-4.0, 1.0, -2.0]];
+        let pivoted = solve_with(a.clone(), PivotStrategy::Partial).unwrap();
+        assert_eq!(vec![1, 0], pivoted.row_permutation);
+        assert_eq!(try_solve(a).unwrap(), pivoted.solution);
+    }
+
+    #[test]
+    fn test_try_solve_non_positive_pivot_column() {
+        // Every candidate pivot is at most zero, so the signed maximum is the
+        // zero on the diagonal, which would report the system singular.
+        let a = mat![[0.0, 1.0, 2.0], [-1.0, 1.0, 0.0]];
+        assert_eq!(Ok(Vector::from(vec![2.0, 2.0])), try_solve(a));
+    }
+
+    #[test]
+    fn test_no_pivoting_loses_accuracy_on_small_pivot() {
+        // x = y = 1, to within 1e-20. A pivot that small is lost in rounding,
+        // so without pivoting the system is reported singular.
+        let a = mat![[1e-20, 1.0, 1.0], [1.0, 1.0, 2.0]];
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 0 }),
+            solve_with(a.clone(), PivotStrategy::None)
+        );
+        let pivoted = solve_with(a, PivotStrategy::Partial).unwrap();
+        assert_eq!(row![1.0, 1.0], pivoted.solution);
+
+        // A pivot of 1e-14 is kept, but costs x most of its digits.
+        let a = mat![[1e-14, 1.0, 2.0], [1.0, 1.0, 1.0]];
+        let unpivoted = solve_with(a.clone(), PivotStrategy::None).unwrap();
+        let pivoted = solve_with(a, PivotStrategy::Partial).unwrap();
+        let error = |x: f64| (x - 1.0 / (1e-14 - 1.0)).abs();
+        assert!(error(unpivoted.solution[0]) > 1e-2);
+        assert!(error(pivoted.solution[0]) < 1e-15);
+
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 0 }),
+            solve_with(mat![[0.0, 1.0, 1.0], [1.0, 0.0, 1.0]], PivotStrategy::None)
+        );
+    }
+
+    #[test]
+    fn test_pivot_lost_in_rounding_is_singular() {
+        // Rank 2, but the last pivot comes out as rounding error, not zero.
+        let a = mat![
+            [1.0, 2.0, 3.0, 1.0],
+            [4.0, 5.0, 6.0, 1.0],
+            [7.0, 8.0, 9.0, 1.0]
+        ];
+        for strategy in [
+            PivotStrategy::None,
+            PivotStrategy::Partial,
+            PivotStrategy::ScaledPartial,
+            PivotStrategy::Complete,
+        ] {
+            assert_eq!(
+                Err(LinalgError::Singular { pivot: 2 }),
+                solve_with(a.clone(), strategy).map(|s| s.solution)
+            );
+        }
+        // Exact entries are only singular on an exact zero.
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 2 }),
+            try_solve(mat![[1, 2, 3, 1], [4, 5, 6, 1], [7, 8, 9, 1]])
+        );
+    }
+
+    #[test]
+    fn test_complete_pivoting_reorders_unknowns() {
+        let a = mat![
+            [1.0, 2.0, 0.0, 3.0],
+            [0.0, 1.0, 8.0, 9.0],
+            [1.0, 0.0, 1.0, 2.0]
+        ];
+        let complete = solve_with(a.clone(), PivotStrategy::Complete).unwrap();
+        assert_eq!(2, complete.col_permutation[0]);
+        assert_eq!(1, complete.row_permutation[0]);
+        for (x, expected) in complete.solution.iter().zip(try_solve(a).unwrap().iter()) {
+            assert!((x - expected).abs() < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_strategies_on_vandermonde_system() {
+        let xs = (0..8).map(|i| 1.0 + i as f64 * 0.5).collect::<Vec<_>>();
+        let v = Matrix::vandermonde(&xs, 7);
+        let b = &v * &Vector::from(vec![1.0; 8]);
+        let residual = |x: &Vector<f64>| (&(&v * x) - &b).norm_inf();
+
+        for &strategy in [
+            PivotStrategy::Partial,
+            PivotStrategy::ScaledPartial,
+            PivotStrategy::Complete,
+        ]
+        .iter()
+        {
+            let augmented = Matrix::from_fn(8, 9, |i, j| if j < 8 { v[i][j] } else { b[i] });
+            let solved = solve_with(augmented, strategy).unwrap();
+            assert!(residual(&solved.solution) < 1e-6 * b.norm_inf());
+        }
+    }
+
+    #[test]
+    fn test_try_solve_rejects_bad_input() {
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 3),
+                found: (2, 2)
+            }),
+            try_solve(mat![[1.0, 2.0], [3.0, 4.0]])
+        );
+        assert_eq!(
+            Err(LinalgError::NonFinite { row: 0, col: 2 }),
+            try_solve(mat![[1.0, 2.0, f64::NAN], [3.0, 4.0, 1.0]])
+        );
+    }
 
     #[test]
     fn test_eliminate() {
@@ -79,8 +618,7 @@ mod tests {
             [726.0, 7895250.0, 49809.0]
         ];
 
-        eliminate(&mut mat, 0);
-        mat.sync();
+        eliminate(&mut mat, 0, &mut |_, _| {});
         assert_eq!(
             mat,
             mat![
@@ -89,4 +627,29 @@ mod tests {
             ]
         )
     }
+
+    #[test]
+    fn test_inverse() {
+        let a = mat![[1.0, 2.0, 3.0], [0.0, 1.0, 4.0], [5.0, 6.0, 0.0]];
+        let product = &a * &inverse(&a).unwrap();
+        for i in 0..3 {
+            for j in 0..3 {
+                let expected = if i == j { 1.0 } else { 0.0 };
+                assert!((product[i][j] - expected).abs() < 1e-12);
+            }
+        }
+
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 1 }),
+            inverse(&mat![[1.0, 2.0], [2.0, 4.0]])
+        );
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 2 }),
+            inverse(&mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]])
+        );
+        assert_eq!(
+            Err(LinalgError::NotSquare { rows: 1, cols: 2 }),
+            inverse(&mat![[1.0, 2.0]])
+        );
+    }
 }
@@ -0,0 +1,324 @@
+//! Orthonormalization by Gram–Schmidt, which builds the thin decomposition
+//! A = QR a column at a time by subtracting from each column of A its
+//! projections onto the columns of Q found so far.
+//!
+//! In floating point the columns of Q drift from orthogonality as A grows ill
+//! conditioned. Classical Gram–Schmidt loses orthogonality in proportion to
+//! κ(A)², modified Gram–Schmidt in proportion to κ(A), and either repeated
+//! once ("twice is enough") keeps it near ε, as Householder QR does.
+//! [`orthogonality_loss`](fn.orthogonality_loss.html) measures the drift.
+use crate::{
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    solver::triangular::{self, DiagonalKind},
+    traits::Transpose,
+    vector::Vector,
+};
+use num_traits::{Float, NumAssignOps};
+
+/// Which variant of Gram–Schmidt to run.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum GramSchmidtMethod {
+    /// Project each column of A onto all of the columns of Q at once.
+    Classical,
+    /// Project onto one column of Q at a time, each projection removed before
+    /// the next is computed. The same work as `Classical`, but more stable.
+    #[default]
+    Modified,
+}
+
+/// How to orthonormalize.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct GramSchmidtSettings {
+    pub method: GramSchmidtMethod,
+    /// Orthogonalize each column a second time, doubling the work.
+    pub reorthogonalize: bool,
+}
+
+/// The thin decomposition A = QR of an m x n `Matrix` A with m ≥ n, computed
+/// by Gram–Schmidt.
+#[derive(Debug, PartialEq, Clone)]
+pub struct GramSchmidt<T> {
+    q: Matrix<T>,
+    r: Matrix<T>,
+}
+
+impl<T> GramSchmidt<T>
+where
+    T: Float + NumAssignOps,
+{
+    /// Orthonormalize the columns of `a`.
+    ///
+    /// Returns an error if `a` has fewer rows than columns, contains a NaN or
+    /// infinite entry, or has linearly dependent columns, naming the first
+    /// which lies in the span of those before it.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix, row, vector::Row, solver::gram_schmidt::{GramSchmidt, GramSchmidtSettings}};
+    ///
+    /// let a = mat![[3.0, 1.0], [4.0, 2.0], [0.0, 5.0]];
+    /// let gs = GramSchmidt::new(&a, GramSchmidtSettings::default()).unwrap();
+    ///
+    /// assert_eq!(row![0.6, 0.8, 0.0], gs.q().col(0));
+    /// assert_eq!(5.0, gs.r()[0][0]);
+    /// assert!(gs.orthogonality_loss() < 1e-15);
+    /// ```
+    pub fn new(a: &Matrix<T>, settings: GramSchmidtSettings) -> Result<Self> {
+        let (m, n) = (a.rows, a.cols);
+        if m < n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (n, n),
+                found: (m, n),
+            });
+        }
+        a.check_finite()?;
+
+        let passes = if settings.reorthogonalize { 2 } else { 1 };
+        let tolerance = T::epsilon() * T::from(m).unwrap();
+        let mut q: Vec<Vec<T>> = Vec::with_capacity(n);
+        let mut r = Matrix::new(n, n);
+
+        for j in 0..n {
+            let mut v: Vec<T> = a.col(j).iter().copied().collect();
+            let original_norm = norm(&v);
+
+            for _ in 0..passes {
+                match settings.method {
+                    GramSchmidtMethod::Classical => {
+                        let coefficients: Vec<T> = q.iter().map(|qi| dot(qi, &v)).collect();
+                        for (i, (qi, &rij)) in q.iter().zip(coefficients.iter()).enumerate() {
+                            subtract_scaled(&mut v, qi, rij);
+                            r[i][j] += rij;
+                        }
+                    }
+                    GramSchmidtMethod::Modified => {
+                        for (i, qi) in q.iter().enumerate() {
+                            let rij = dot(qi, &v);
+                            subtract_scaled(&mut v, qi, rij);
+                            r[i][j] += rij;
+                        }
+                    }
+                }
+            }
+
+            let rjj = norm(&v);
+            if rjj <= tolerance * original_norm {
+                return Err(LinalgError::Singular { pivot: j });
+            }
+            r[j][j] = rjj;
+            q.push(v.into_iter().map(|x| x / rjj).collect());
+        }
+
+        Ok(GramSchmidt {
+            q: Matrix::from_fn(m, n, |i, j| q[j][i]),
+            r,
+        })
+    }
+
+    /// The m x n factor Q, whose columns are an orthonormal basis for the
+    /// column space of A.
+    pub fn q(&self) -> &Matrix<T> {
+        &self.q
+    }
+
+    /// The n x n upper triangular factor R.
+    pub fn r(&self) -> &Matrix<T> {
+        &self.r
+    }
+
+    /// How far the columns of Q are from orthonormal, ‖QᵀQ - I‖_F.
+    pub fn orthogonality_loss(&self) -> T {
+        orthogonality_loss(&self.q)
+    }
+
+    /// Finds the x minimizing ‖Ax - b‖₂ by solving Rx = Qᵀb, as accurate as Q
+    /// is orthogonal.
+    ///
+    /// Returns an error if `b` does not have as many entries as A has rows.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix, row, vector::Row, solver::gram_schmidt::{GramSchmidt, GramSchmidtSettings}};
+    ///
+    /// // Fit y = c₀ + c₁x through (0, 1), (1, 3), (2, 5)
+    /// let a: Matrix<f64> = mat![[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]];
+    /// let gs = GramSchmidt::new(&a, GramSchmidtSettings::default()).unwrap();
+    /// let x = gs.solve_least_squares(&row![1.0, 3.0, 5.0]).unwrap();
+    ///
+    /// assert!((x[0] - 1.0).abs() < 1e-12);
+    /// assert!((x[1] - 2.0).abs() < 1e-12);
+    /// ```
+    pub fn solve_least_squares(&self, b: &Vector<T>) -> Result<Vector<T>> {
+        if b.len() != self.q.rows {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (self.q.rows, 1),
+                found: (b.len(), 1),
+            });
+        }
+        let qtb = &self.q.transpose() * b;
+        triangular::back_substitution(&self.r, &qtb, DiagonalKind::NonUnit)
+    }
+}
+
+/// Orthonormalize `vectors`, returning an orthonormal basis for their span,
+/// the k-th basis vector in the span of the first k of `vectors`.
+///
+/// Returns an error if `vectors` is empty, they differ in length, there are more
+/// of them than entries in each, any has a NaN or infinite entry, or they are
+/// linearly dependent.
+///
+/// # Example
+/// ```
+/// use launearalg::{row, vector::Row, solver::gram_schmidt::{self, GramSchmidtSettings}};
+///
+/// let vectors = [row![0.0, 2.0], row![3.0, 1.0]];
+/// let basis = gram_schmidt::orthonormalize(&vectors, GramSchmidtSettings::default()).unwrap();
+///
+/// assert_eq!(vec![row![0.0, 1.0], row![1.0, 0.0]], basis);
+/// ```
+pub fn orthonormalize<T>(
+    vectors: &[Vector<T>],
+    settings: GramSchmidtSettings,
+) -> Result<Vec<Vector<T>>>
+where
+    T: Float + NumAssignOps,
+{
+    let m = vectors.first().ok_or(LinalgError::Empty)?.len();
+    if let Some(v) = vectors.iter().find(|v| v.len() != m) {
+        return Err(LinalgError::DimensionMismatch {
+            expected: (m, 1),
+            found: (v.len(), 1),
+        });
+    }
+
+    let a = Matrix::from_fn(m, vectors.len(), |i, j| vectors[j][i]);
+    let q = GramSchmidt::new(&a, settings)?.q;
+    Ok((0..q.cols).map(|j| q.col(j)).collect())
+}
+
+/// How far the columns of `q` are from orthonormal, ‖QᵀQ - I‖_F, for
+/// comparing any method of orthonormalization.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, solver::{gram_schmidt, qr::QrFactorization}};
+///
+/// let a = mat![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+/// let householder = QrFactorization::new(&a).unwrap().thin_q();
+///
+/// assert!(gram_schmidt::orthogonality_loss(&householder) < 1e-15);
+/// assert_eq!(2f64.sqrt(), gram_schmidt::orthogonality_loss(&mat![[1.0, 1.0], [0.0, 0.0]]));
+/// ```
+pub fn orthogonality_loss<T>(q: &Matrix<T>) -> T
+where
+    T: Float + NumAssignOps,
+{
+    let mut qtq = &q.transpose() * q;
+    for i in 0..qtq.rows {
+        qtq[i][i] -= T::one();
+    }
+    qtq.norm_fro()
+}
+
+fn dot<T: Float>(x: &[T], y: &[T]) -> T {
+    x.iter()
+        .zip(y.iter())
+        .fold(T::zero(), |acc, (&a, &b)| acc + a * b)
+}
+
+fn norm<T: Float>(v: &[T]) -> T {
+    dot(v, v).sqrt()
+}
+
+// v -= s * q
+fn subtract_scaled<T>(v: &mut [T], q: &[T], s: T)
+where
+    T: Float + NumAssignOps,
+{
+    v.iter_mut()
+        .zip(q.iter())
+        .for_each(|(vi, &qi)| *vi -= s * qi);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mat, solver::qr::QrFactorization, test_util::hilbert};
+
+    fn loss(method: GramSchmidtMethod, reorthogonalize: bool) -> f64 {
+        let settings = GramSchmidtSettings {
+            method,
+            reorthogonalize,
+        };
+        GramSchmidt::new(&hilbert(12, 8), settings)
+            .unwrap()
+            .orthogonality_loss()
+    }
+
+    #[test]
+    fn test_qr_reconstructs_a() {
+        let a = mat![
+            [12.0, -51.0, 4.0],
+            [6.0, 167.0, -68.0],
+            [-4.0, 24.0, -41.0],
+            [1.0, 0.0, 2.0]
+        ];
+        for &method in &[GramSchmidtMethod::Classical, GramSchmidtMethod::Modified] {
+            for &reorthogonalize in &[false, true] {
+                let gs = GramSchmidt::new(
+                    &a,
+                    GramSchmidtSettings {
+                        method,
+                        reorthogonalize,
+                    },
+                )
+                .unwrap();
+                let product = gs.q() * gs.r();
+                assert!((&product - &a).norm_fro() < 1e-12 * a.norm_fro());
+                assert!(gs.orthogonality_loss() < 1e-14);
+                assert!((0..3).all(|i| (0..i).all(|j| gs.r()[i][j] == 0.0)));
+            }
+        }
+    }
+
+    #[test]
+    fn test_loss_of_orthogonality_ranks_the_methods() {
+        let classical = loss(GramSchmidtMethod::Classical, false);
+        let modified = loss(GramSchmidtMethod::Modified, false);
+        let householder =
+            orthogonality_loss(&QrFactorization::new(&hilbert(12, 8)).unwrap().thin_q());
+
+        assert!(classical > 1e-2);
+        assert!(modified < 1e-3 && modified > 1e3 * householder);
+        assert!(loss(GramSchmidtMethod::Classical, true) < 1e-14);
+        assert!(loss(GramSchmidtMethod::Modified, true) < 1e-14);
+    }
+
+    #[test]
+    fn test_dependent_vectors() {
+        let vectors = [
+            Vector::from(vec![1.0, 0.0, 1.0]),
+            Vector::from(vec![0.0, 1.0, 0.0]),
+            Vector::from(vec![2.0, 3.0, 2.0]),
+        ];
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 2 }),
+            orthonormalize(&vectors, GramSchmidtSettings::default())
+        );
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (3, 1),
+                found: (2, 1)
+            }),
+            orthonormalize(
+                &[vectors[0].clone(), Vector::from(vec![1.0, 2.0])],
+                GramSchmidtSettings::default()
+            )
+        );
+        assert_eq!(
+            Err(LinalgError::Empty),
+            orthonormalize::<f64>(&[], GramSchmidtSettings::default())
+        );
+    }
+}
@@ -0,0 +1,685 @@
+//! Iterative solvers for Ax = b, which improve a guess x until the residual
+//! b - Ax is small enough, rather than factorizing A.
+//!
+//! The conjugate gradient methods use A only through the products Ax and Aᵀx,
+//! so take any [`LinearOperator`](../../operator/trait.LinearOperator.html),
+//! such as an `FnOperator` for a matrix which is never stored. Jacobi needs
+//! the diagonal of A as well, and [`jacobi_operator`](fn.jacobi_operator.html)
+//! takes it alongside an operator.
+//!
+//! Gauss–Seidel and SOR cannot be driven by products: a sweep updates x a
+//! component at a time, each row of A seeing the components before it already
+//! updated and those after it not, and no single product Ax can mix the two.
+//! They take a [`RowAccess`](trait.RowAccess.html) instead, which visits the
+//! entries of A a row at a time.
+use crate::{
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    operator::LinearOperator,
+    vector::Vector,
+};
+use num_traits::{Float, NumAssignOps};
+
+/// A square system the stationary methods (Jacobi, Gauss–Seidel and SOR) can
+/// sweep a row at a time, so that it never has to be stored densely.
+///
+/// Implemented by `Matrix`, `sparse::CsrMatrix`, and the banded
+/// `banded::TridiagonalMatrix` and `banded::BandedMatrix`. The conjugate gradient
+/// methods, and `jacobi_operator`, need only products with A, so take any
+/// [`LinearOperator`](../../operator/trait.LinearOperator.html) instead.
+pub trait RowAccess<T> {
+    /// The shape (rows, cols).
+    fn dims(&self) -> (usize, usize);
+
+    /// The entry at (i, j).
+    fn get(&self, i: usize, j: usize) -> T;
+
+    /// Calls `f(j, a_ij)` for every stored entry a_ij of row `i`. Entries not
+    /// visited are zero.
+    fn for_each_in_row<F>(&self, i: usize, f: F)
+    where
+        F: FnMut(usize, T);
+}
+
+impl<T> RowAccess<T> for Matrix<T>
+where
+    T: Float,
+{
+    fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn get(&self, i: usize, j: usize) -> T {
+        self[i][j]
+    }
+
+    fn for_each_in_row<F>(&self, i: usize, mut f: F)
+    where
+        F: FnMut(usize, T),
+    {
+        for (j, aij) in self[i].iter().enumerate() {
+            f(j, *aij);
+        }
+    }
+}
+
+/// When an iterative solver should stop.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct IterativeSettings<T> {
+    /// Stop once the relative residual ‖b - Ax‖ / ‖b‖ is at or below this.
+    pub tolerance: T,
+    /// Give up after this many iterations.
+    pub max_iterations: usize,
+}
+
+impl<T> Default for IterativeSettings<T>
+where
+    T: Float,
+{
+    /// A tolerance of √ε, and at most 1000 iterations.
+    fn default() -> Self {
+        IterativeSettings {
+            tolerance: T::epsilon().sqrt(),
+            max_iterations: 1000,
+        }
+    }
+}
+
+/// The outcome of an iterative solve.
+///
+/// A solver which runs out of iterations still reports its last iterate, so
+/// that the convergence of different methods can be compared.
+#[derive(Debug, PartialEq, Clone)]
+pub struct IterativeReport<T> {
+    /// The last iterate x.
+    pub solution: Vector<T>,
+    /// Number of iterations performed.
+    pub iterations: usize,
+    /// Relative residual ‖b - Ax‖ / ‖b‖ after each iteration.
+    pub residuals: Vec<T>,
+    /// Whether the tolerance was met within the allowed iterations.
+    pub converged: bool,
+}
+
+/// Solves Ax = b by Jacobi iteration, starting from x = 0.
+///
+/// Every component of the next iterate is computed from the previous one only.
+/// Converges for strictly diagonally dominant A.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, row, matrix::Matrix, vector::Row, solver::iterative::*};
+///
+/// let a: Matrix<f64> = mat![[4.0, 1.0], [1.0, 3.0]];
+/// let report = jacobi(&a, &row![5.0, 4.0], &IterativeSettings::default()).unwrap();
+///
+/// assert!(report.converged);
+/// assert!((report.solution[0] - 1.0).abs() < 1e-6);
+/// assert!((report.solution[1] - 1.0).abs() < 1e-6);
+/// ```
+pub fn jacobi<T, A>(
+    a: &A,
+    b: &Vector<T>,
+    settings: &IterativeSettings<T>,
+) -> Result<IterativeReport<T>>
+where
+    T: Float + NumAssignOps,
+    A: RowAccess<T>,
+{
+    check_system(a, b)?;
+    let diagonal = diagonal(a)?;
+    jacobi_with(|x| mul_vec(a, x), &diagonal, b, settings)
+}
+
+/// Solves Ax = b by Jacobi iteration, starting from x = 0, for an operator A
+/// whose diagonal is `diagonal`.
+///
+/// Each iteration makes the update x ← x + D⁻¹(b - Ax) of `jacobi`, for the
+/// diagonal D, so needs just one product with A. Returns an error if
+/// `diagonal` has the wrong length or a zero.
+///
+/// # Example
+/// ```
+/// use launearalg::{operator::FnOperator, row, vector::{Row, Vector}, solver::iterative::*};
+///
+/// // [4 1; 1 3], applied without being stored.
+/// let a = FnOperator::symmetric(2, |x: &Vector<f64>| row![4.0 * x[0] + x[1], x[0] + 3.0 * x[1]]);
+/// let report = jacobi_operator(&a, &row![4.0, 3.0], &row![5.0, 4.0], &IterativeSettings::default()).unwrap();
+///
+/// assert!(report.converged);
+/// assert!((report.solution[0] - 1.0).abs() < 1e-6);
+/// ```
+pub fn jacobi_operator<T, A>(
+    a: &A,
+    diagonal: &Vector<T>,
+    b: &Vector<T>,
+    settings: &IterativeSettings<T>,
+) -> Result<IterativeReport<T>>
+where
+    T: Float + NumAssignOps,
+    A: LinearOperator<T>,
+{
+    let n = check_dims(a.dims(), b)?;
+    if diagonal.len() != n {
+        return Err(LinalgError::DimensionMismatch {
+            expected: (n, 1),
+            found: (diagonal.len(), 1),
+        });
+    }
+    if let Some(pivot) = diagonal.iter().position(|aii| *aii == T::zero()) {
+        return Err(LinalgError::Singular { pivot });
+    }
+    jacobi_with(|x| a.apply(x), diagonal, b, settings)
+}
+
+// Jacobi iteration with `mul` giving Ax. The residual of each iterate is the
+// one the next iteration updates with, so it costs a single product.
+fn jacobi_with<T, M>(
+    mul: M,
+    diagonal: &Vector<T>,
+    b: &Vector<T>,
+    settings: &IterativeSettings<T>,
+) -> Result<IterativeReport<T>>
+where
+    T: Float + NumAssignOps,
+    M: Fn(&Vector<T>) -> Vector<T>,
+{
+    // The residual of x = 0.
+    let mut r = b.clone();
+    iterate(b, settings, |_, x| {
+        for i in 0..x.len() {
+            x[i] += r[i] / diagonal[i];
+        }
+        r = b - &mul(x);
+        Ok(r.norm_2())
+    })
+}
+
+/// Solves Ax = b by Gauss–Seidel iteration, starting from x = 0.
+///
+/// Like Jacobi, but each component is updated in place, so later components
+/// already see the new values of earlier ones. Converges for strictly diagonally
+/// dominant, or symmetric positive-definite, A.
+pub fn gauss_seidel<T, A>(
+    a: &A,
+    b: &Vector<T>,
+    settings: &IterativeSettings<T>,
+) -> Result<IterativeReport<T>>
+where
+    T: Float + NumAssignOps,
+    A: RowAccess<T>,
+{
+    sor(a, b, T::one(), settings)
+}
+
+/// Solves Ax = b by successive over-relaxation, starting from x = 0.
+///
+/// Each Gauss–Seidel update is scaled by the relaxation factor `omega`;
+/// `omega` = 1 is exactly Gauss–Seidel. Only 0 < `omega` < 2 can converge, so
+/// any other `omega`, or a NaN, is rejected with `LinalgError::InvalidParameter`.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, row, matrix::Matrix, vector::Row, solver::iterative::*};
+///
+/// let a: Matrix<f64> = mat![[4.0, -1.0, 0.0], [-1.0, 4.0, -1.0], [0.0, -1.0, 4.0]];
+/// let b = row![2.0, 4.0, 10.0];
+/// let settings = IterativeSettings::default();
+///
+/// let gs = gauss_seidel(&a, &b, &settings).unwrap();
+/// let sor = sor(&a, &b, 1.05, &settings).unwrap();
+/// assert!(sor.iterations <= gs.iterations);
+/// ```
+pub fn sor<T, A>(
+    a: &A,
+    b: &Vector<T>,
+    omega: T,
+    settings: &IterativeSettings<T>,
+) -> Result<IterativeReport<T>>
+where
+    T: Float + NumAssignOps,
+    A: RowAccess<T>,
+{
+    let two = T::one() + T::one();
+    if !(omega > T::zero() && omega < two) {
+        return Err(LinalgError::InvalidParameter { name: "omega" });
+    }
+    let n = check_system(a, b)?;
+    let diagonal = diagonal(a)?;
+
+    iterate(b, settings, |_, x| {
+        for i in 0..n {
+            let mut sum = b[i];
+            a.for_each_in_row(i, |j, aij| {
+                if j != i {
+                    sum -= aij * x[j];
+                }
+            });
+            x[i] = (T::one() - omega) * x[i] + omega * sum / diagonal[i];
+        }
+        // The sweep only saw part of each row updated, so the residual takes
+        // another.
+        Ok((b - &mul_vec(a, x)).norm_2())
+    })
+}
+
+/// Solves Ax = b by the conjugate gradient method, starting from x = 0.
+///
+/// A must be symmetric positive definite, in which case convergence takes at
+/// most n iterations in exact arithmetic. Symmetry is checked by comparing Ax
+/// with Aᵀx for a single probe vector x, as an operator's entries may not be
+/// at hand. A direction of non-positive curvature is reported as
+/// `LinalgError::NotPositiveDefinite`, with the iteration it was met on.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, row, matrix::Matrix, vector::Row, solver::iterative::*};
+///
+/// let a: Matrix<f64> = mat![[4.0, 1.0], [1.0, 3.0]];
+/// let report = conjugate_gradient(&a, &row![1.0, 2.0], &IterativeSettings::default()).unwrap();
+///
+/// assert!(report.converged);
+/// assert!(report.iterations <= 2);
+/// ```
+pub fn conjugate_gradient<T, A>(
+    a: &A,
+    b: &Vector<T>,
+    settings: &IterativeSettings<T>,
+) -> Result<IterativeReport<T>>
+where
+    T: Float + NumAssignOps,
+    A: LinearOperator<T>,
+{
+    let n = check_dims(a.dims(), b)?;
+    let probe = Vector::from(
+        (0..n)
+            .map(|i| T::one() / T::from(i + 1).unwrap())
+            .collect::<Vec<T>>(),
+    );
+    let (ax, atx) = (a.apply(&probe), a.apply_transpose(&probe));
+    if (&ax - &atx).norm_2() > T::epsilon().sqrt() * ax.norm_2().max(atx.norm_2()) {
+        return Err(LinalgError::NotSymmetric);
+    }
+
+    let mut r = b.clone();
+    let mut p = r.clone();
+    let mut rr = r.dot(&r);
+
+    iterate(b, settings, |iteration, x| {
+        let ap = a.apply(&p);
+        let curvature = p.dot(&ap);
+        if curvature <= T::zero() {
+            return Err(LinalgError::NotPositiveDefinite { pivot: iteration });
+        }
+
+        let alpha = rr / curvature;
+        *x += &p * alpha;
+        r -= &ap * alpha;
+
+        let rr_next = r.dot(&r);
+        let beta = rr_next / rr;
+        rr = rr_next;
+        p = &r + &(&p * beta);
+        Ok(r.norm_2())
+    })
+}
+
+/// Solves Ax = b by the conjugate gradient method applied to the normal
+/// equations AᵀAx = Aᵀb (CGNR), starting from x = 0.
+///
+/// Unlike `conjugate_gradient`, A need not be symmetric, only non-singular, at
+/// the price of a product with Aᵀ as well as A each iteration, and convergence
+/// governed by κ(A)² rather than κ(A). A direction A maps to zero is reported
+/// as `LinalgError::Singular`, with the iteration it was met on.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, row, matrix::Matrix, vector::Row, solver::iterative::*};
+///
+/// let a: Matrix<f64> = mat![[2.0, 1.0], [-1.0, 3.0]];
+/// let report = conjugate_gradient_normal(&a, &row![3.0, 2.0], &IterativeSettings::default()).unwrap();
+///
+/// assert!(report.converged);
+/// assert!((report.solution[0] - 1.0).abs() < 1e-6);
+/// ```
+pub fn conjugate_gradient_normal<T, A>(
+    a: &A,
+    b: &Vector<T>,
+    settings: &IterativeSettings<T>,
+) -> Result<IterativeReport<T>>
+where
+    T: Float + NumAssignOps,
+    A: LinearOperator<T>,
+{
+    check_dims(a.dims(), b)?;
+
+    let mut r = b.clone();
+    let mut z = a.apply_transpose(&r);
+    let mut p = z.clone();
+    let mut zz = z.dot(&z);
+
+    iterate(b, settings, |iteration, x| {
+        let w = a.apply(&p);
+        let ww = w.dot(&w);
+        if ww == T::zero() {
+            return Err(LinalgError::Singular { pivot: iteration });
+        }
+
+        let alpha = zz / ww;
+        *x += &p * alpha;
+        r -= &w * alpha;
+
+        z = a.apply_transpose(&r);
+        let zz_next = z.dot(&z);
+        let beta = zz_next / zz;
+        zz = zz_next;
+        p = &z + &(&p * beta);
+        Ok(r.norm_2())
+    })
+}
+
+// Drives `step`, which advances the iterate x from 0 and gives its residual
+// ‖b - Ax‖, until the relative residual meets the tolerance or the iterations
+// run out. Jacobi and the Krylov methods keep their residuals up to date as
+// they go, so need no extra product to check them; SOR's sweep leaves its
+// residual stale, so it takes one more pass over A.
+fn iterate<T, F>(
+    b: &Vector<T>,
+    settings: &IterativeSettings<T>,
+    mut step: F,
+) -> Result<IterativeReport<T>>
+where
+    T: Float + NumAssignOps,
+    F: FnMut(usize, &mut Vector<T>) -> Result<T>,
+{
+    let b_norm = b.norm_2();
+    let scale = if b_norm == T::zero() {
+        T::one()
+    } else {
+        b_norm
+    };
+
+    let mut x = Vector::from(vec![T::zero(); b.len()]);
+    let mut residuals = Vec::new();
+    let mut converged = b_norm == T::zero();
+
+    while !converged && residuals.len() < settings.max_iterations {
+        let residual = step(residuals.len(), &mut x)? / scale;
+        if !residual.is_finite() {
+            return Err(LinalgError::NoConvergence {
+                iterations: residuals.len(),
+            });
+        }
+
+        residuals.push(residual);
+        converged = residual <= settings.tolerance;
+    }
+
+    Ok(IterativeReport {
+        solution: x,
+        iterations: residuals.len(),
+        residuals,
+        converged,
+    })
+}
+
+// Checks A, of shape `dims`, is square and matches b, returning its size.
+fn check_dims<T: Float>(dims: (usize, usize), b: &Vector<T>) -> Result<usize> {
+    let (rows, cols) = dims;
+    if rows != cols {
+        return Err(LinalgError::NotSquare { rows, cols });
+    }
+    if b.len() != rows {
+        return Err(LinalgError::DimensionMismatch {
+            expected: (rows, 1),
+            found: (b.len(), 1),
+        });
+    }
+    Ok(rows)
+}
+
+// Checks A is square, matches b, and is finite, returning its size.
+fn check_system<T, A>(a: &A, b: &Vector<T>) -> Result<usize>
+where
+    T: Float,
+    A: RowAccess<T>,
+{
+    let rows = check_dims(a.dims(), b)?;
+    for row in 0..rows {
+        let mut non_finite = None;
+        a.for_each_in_row(row, |col, aij| {
+            if non_finite.is_none() && !aij.is_finite() {
+                non_finite = Some(col);
+            }
+        });
+        if let Some(col) = non_finite {
+            return Err(LinalgError::NonFinite { row, col });
+        }
+    }
+    Ok(rows)
+}
+
+fn diagonal<T, A>(a: &A) -> Result<Vector<T>>
+where
+    T: Float,
+    A: RowAccess<T>,
+{
+    let n = a.dims().0;
+    let diagonal = Vector::from((0..n).map(|i| a.get(i, i)).collect::<Vec<T>>());
+    match diagonal.iter().position(|aii| *aii == T::zero()) {
+        Some(pivot) => Err(LinalgError::Singular { pivot }),
+        None => Ok(diagonal),
+    }
+}
+
+fn mul_vec<T, A>(a: &A, v: &Vector<T>) -> Vector<T>
+where
+    T: Float,
+    A: RowAccess<T>,
+{
+    Vector::from(
+        (0..a.dims().0)
+            .map(|i| {
+                let mut sum = T::zero();
+                a.for_each_in_row(i, |j, aij| sum = sum + aij * v[j]);
+                sum
+            })
+            .collect::<Vec<T>>(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        banded::BandedMatrix,
+        mat,
+        operator::FnOperator,
+        row,
+        solver::lu::LuFactorization,
+        sparse::CsrMatrix,
+        test_util::{self, assert_vector_close},
+        traits::Transpose,
+        vector::Row,
+    };
+
+    // The dense spline system, with a right-hand side to solve for.
+    fn spline_system(n: usize) -> (Matrix<f64>, Vector<f64>) {
+        let b = Vector::from((0..n).map(|i| (i as f64).sin()).collect::<Vec<f64>>());
+        (test_util::spline_system(n).to_matrix(), b)
+    }
+
+    fn assert_solves(a: &Matrix<f64>, b: &Vector<f64>, report: &IterativeReport<f64>) {
+        let expected = LuFactorization::new(a).unwrap().solve(b);
+        assert!(report.converged);
+        assert_eq!(report.iterations, report.residuals.len());
+        for i in 0..b.len() {
+            assert!((expected[i] - report.solution[i]).abs() < 1e-6);
+        }
+    }
+
+    #[test]
+    fn test_all_methods_solve_spline_system() {
+        let (a, b) = spline_system(20);
+        let settings = IterativeSettings::default();
+
+        let jacobi = jacobi(&a, &b, &settings).unwrap();
+        let gauss_seidel = gauss_seidel(&a, &b, &settings).unwrap();
+        let sor = sor(&a, &b, 1.1, &settings).unwrap();
+        let cg = conjugate_gradient(&a, &b, &settings);
+
+        assert_solves(&a, &b, &jacobi);
+        assert_solves(&a, &b, &gauss_seidel);
+        assert_solves(&a, &b, &sor);
+        assert!(gauss_seidel.iterations < jacobi.iterations);
+        // The spline system isn't symmetric: its first and last rows are.
+        assert_eq!(Err(LinalgError::NotSymmetric), cg);
+    }
+
+    #[test]
+    fn test_sor_rejects_omega_outside_0_2() {
+        let (a, b) = spline_system(5);
+        let settings = IterativeSettings::default();
+
+        for &omega in &[0.0, -0.5, 2.0, 3.0, f64::NAN, f64::INFINITY] {
+            assert_eq!(
+                Err(LinalgError::InvalidParameter { name: "omega" }),
+                sor(&a, &b, omega, &settings)
+            );
+        }
+        assert!(sor(&a, &b, 1.9, &settings).is_ok());
+    }
+
+    #[test]
+    fn test_jacobi_operator_matches_jacobi() {
+        let t = test_util::spline_system(20);
+        let (a, b) = spline_system(20);
+        let settings = IterativeSettings::default();
+
+        let dense = jacobi(&a, &b, &settings).unwrap();
+        let operator =
+            jacobi_operator(&t, &Vector::from(t.diagonal().to_vec()), &b, &settings).unwrap();
+        assert_solves(&a, &b, &operator);
+        assert_eq!(dense.iterations, operator.iterations);
+        assert_vector_close(&dense.solution, &operator.solution);
+
+        let mut zero = Vector::from(t.diagonal().to_vec());
+        zero[3] = 0.0;
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 3 }),
+            jacobi_operator(&t, &zero, &b, &settings)
+        );
+    }
+
+    #[test]
+    fn test_stationary_methods_on_tridiagonal() {
+        let t = test_util::spline_system(20);
+        let (a, b) = spline_system(20);
+        let settings = IterativeSettings::default();
+
+        let dense = gauss_seidel(&a, &b, &settings).unwrap();
+        let banded = gauss_seidel(&t, &b, &settings).unwrap();
+        assert_solves(&a, &b, &banded);
+        assert_eq!(dense.iterations, banded.iterations);
+        assert_vector_close(&dense.solution, &banded.solution);
+
+        let dense = sor(&a, &b, 1.1, &settings).unwrap();
+        let banded = sor(&t, &b, 1.1, &settings).unwrap();
+        assert_solves(&a, &b, &banded);
+        assert_eq!(dense.iterations, banded.iterations);
+        assert_vector_close(&dense.solution, &banded.solution);
+
+        let banded = sor(&BandedMatrix::from(&t), &b, 1.1, &settings).unwrap();
+        assert_vector_close(&dense.solution, &banded.solution);
+    }
+
+    #[test]
+    fn test_conjugate_gradient_spd() {
+        let a = mat![[4.0, 1.0, 0.0], [1.0, 4.0, 1.0], [0.0, 1.0, 4.0]];
+        let b = row![1.0, 2.0, 3.0];
+        let report = conjugate_gradient(&a, &b, &IterativeSettings::default()).unwrap();
+
+        assert_solves(&a, &b, &report);
+        assert!(report.iterations <= 3);
+    }
+
+    #[test]
+    fn test_conjugate_gradient_rejects_indefinite() {
+        let a = mat![[1.0, 0.0], [0.0, -1.0]];
+        assert_eq!(
+            Err(LinalgError::NotPositiveDefinite { pivot: 0 }),
+            conjugate_gradient(&a, &row![0.0, 1.0], &IterativeSettings::default())
+        );
+    }
+
+    #[test]
+    fn test_conjugate_gradient_normal_on_operators() {
+        let (a, b) = spline_system(20);
+        let settings = IterativeSettings::default();
+        let dense = conjugate_gradient_normal(&a, &b, &settings).unwrap();
+        assert_solves(&a, &b, &dense);
+
+        let at = a.transpose();
+        let closures = FnOperator::new(20, 20, |x: &Vector<f64>| &a * x, |x: &Vector<f64>| &at * x);
+        assert_eq!(
+            dense,
+            conjugate_gradient_normal(&CsrMatrix::from(&a), &b, &settings).unwrap()
+        );
+        assert_eq!(
+            dense,
+            conjugate_gradient_normal(&closures, &b, &settings).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_conjugate_gradient_on_stencil() {
+        // Steady heat flow along a rod held at 0 at both ends, -u'' = 1.
+        let n = 30;
+        let stencil = FnOperator::symmetric(n, |u: &Vector<f64>| {
+            (0..n)
+                .map(|i| {
+                    let left = if i > 0 { u[i - 1] } else { 0.0 };
+                    let right = if i + 1 < n { u[i + 1] } else { 0.0 };
+                    2.0 * u[i] - left - right
+                })
+                .collect()
+        });
+        let a = Matrix::from_fn(n, n, |i, j| match () {
+            _ if i == j => 2.0,
+            _ if i + 1 == j || j + 1 == i => -1.0,
+            _ => 0.0,
+        });
+        let b = Vector::from(vec![1.0; n]);
+
+        let report = conjugate_gradient(&stencil, &b, &IterativeSettings::default()).unwrap();
+        assert_solves(&a, &b, &report);
+        assert!(report.iterations <= n);
+
+        let lopsided = FnOperator::new(n, n, |u: &Vector<f64>| &a * u, |u: &Vector<f64>| u.clone());
+        assert_eq!(
+            Err(LinalgError::NotSymmetric),
+            conjugate_gradient(&lopsided, &b, &IterativeSettings::default())
+        );
+    }
+
+    #[test]
+    fn test_reports_non_convergence() {
+        let a = mat![[1.0, 2.0], [3.0, 1.0]];
+        let settings = IterativeSettings {
+            tolerance: 1e-10,
+            max_iterations: 5,
+        };
+        let report = jacobi(&a, &row![1.0, 1.0], &settings).unwrap();
+        assert!(!report.converged);
+        assert_eq!(5, report.iterations);
+    }
+
+    #[test]
+    fn test_rejects_zero_diagonal() {
+        let a = mat![[0.0, 1.0], [1.0, 0.0]];
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 0 }),
+            jacobi(&a, &row![1.0, 1.0], &IterativeSettings::default())
+        );
+    }
+}
@@ -0,0 +1,322 @@
+use crate::{
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    solver::{
+        gauss,
+        triangular::{self, DiagonalKind},
+    },
+    vector::Vector,
+};
+use num_traits::{Float, NumAssignOps};
+
+/// The result of decomposing a square `Matrix` A into PA = LU with partial pivoting.
+///
+/// L is unit lower triangular and U is upper triangular. Both are kept packed
+/// into a single `Matrix`, with the unit diagonal of L left implicit. The
+/// factorization can be reused to solve Ax = b for as many right-hand sides
+/// as needed without repeating the elimination.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LuFactorization<T> {
+    lu: Matrix<T>,
+    permutation: Vec<usize>,
+    swaps: usize,
+}
+
+impl<T> LuFactorization<T>
+where
+    T: Float + NumAssignOps,
+{
+    /// Decompose the square `Matrix` `a`, choosing at each step the row whose
+    /// entry in the pivot column has the largest magnitude.
+    ///
+    /// Returns an error if `a` is not square, contains a NaN or infinite entry,
+    /// or is singular, which includes a pivot within rounding error of zero,
+    /// |p| ≤ nε·max|aᵢⱼ|.
+    ///
+    /// Integer entries would truncate each multiplier, so are not accepted; for
+    /// exact integer elimination see [`bareiss`](../bareiss/index.html).
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix, solver::lu::LuFactorization};
+    ///
+    /// let a = mat![[4.0, 3.0], [6.0, 3.0]];
+    /// let lu = LuFactorization::new(&a).unwrap();
+    ///
+    /// assert_eq!(mat![[1.0, 0.0], [2.0 / 3.0, 1.0]], lu.l());
+    /// assert_eq!(mat![[6.0, 3.0], [0.0, 1.0]], lu.u());
+    /// assert_eq!(&[1, 0], lu.permutation());
+    /// ```
+    ///
+    /// Integer entries do not compile:
+    /// ```compile_fail
+    /// use launearalg::{mat, matrix::Matrix, solver::lu::LuFactorization};
+    ///
+    /// let lu = LuFactorization::new(&mat![[2i64, 1], [1, 1]]);
+    /// ```
+    pub fn new(a: &Matrix<T>) -> Result<Self> {
+        if a.rows != a.cols {
+            return Err(LinalgError::NotSquare {
+                rows: a.rows,
+                cols: a.cols,
+            });
+        }
+        a.check_finite()?;
+
+        let n = a.rows;
+        let max_abs = a
+            .iter()
+            .flatten()
+            .fold(T::zero(), |acc, x| acc.max(x.abs()));
+        let tolerance = gauss::float_pivot_tolerance(n, max_abs);
+        let mut lu = a.clone();
+        let mut permutation: Vec<usize> = (0..n).collect();
+        let mut swaps = 0;
+
+        for k in 0..n {
+            let pivot_row = (k..n)
+                .max_by(|x, y| {
+                    lu[*x][k]
+                        .abs()
+                        .partial_cmp(&lu[*y][k].abs())
+                        .expect("matrix entries must be ordered")
+                })
+                .unwrap();
+
+            if lu[pivot_row][k].abs() <= tolerance {
+                return Err(LinalgError::Singular { pivot: k });
+            }
+
+            if pivot_row != k {
+                lu.swap_rows_in_place(k, pivot_row);
+                permutation.swap(k, pivot_row);
+                swaps += 1;
+            }
+
+            let pivot = lu[k][k];
+            for i in k + 1..n {
+                let multiplier = lu[i][k] / pivot;
+                lu[i][k] = multiplier;
+                for j in k + 1..n {
+                    let scaled = multiplier * lu[k][j];
+                    lu[i][j] -= scaled;
+                }
+            }
+        }
+
+        Ok(LuFactorization {
+            lu,
+            permutation,
+            swaps,
+        })
+    }
+
+    /// Order of the factorized matrix.
+    pub fn size(&self) -> usize {
+        self.lu.rows
+    }
+
+    /// Row permutation applied to A, such that row `i` of PA is row
+    /// `permutation()[i]` of A.
+    pub fn permutation(&self) -> &[usize] {
+        &self.permutation
+    }
+
+    /// Unpacks the unit lower triangular factor L.
+    pub fn l(&self) -> Matrix<T> {
+        let n = self.size();
+        let mut l = Matrix::new(n, n);
+        for i in 0..n {
+            l[i][..i].copy_from_slice(&self.lu[i][..i]);
+            l[i][i] = T::one();
+        }
+        l
+    }
+
+    /// Unpacks the upper triangular factor U.
+    pub fn u(&self) -> Matrix<T> {
+        let n = self.size();
+        let mut u = Matrix::new(n, n);
+        for i in 0..n {
+            u[i][i..].copy_from_slice(&self.lu[i][i..]);
+        }
+        u
+    }
+
+    /// Solve Ax = b for x, reusing the factorization.
+    ///
+    /// # Panics
+    /// Panics if `b` is not the same length as the order of A.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, row, matrix::Matrix, vector::Row, solver::lu::LuFactorization};
+    ///
+    /// let a = mat![[2.0, 1.0], [1.0, 3.0]];
+    /// let lu = LuFactorization::new(&a).unwrap();
+    ///
+    /// assert_eq!(row![1.0, 1.0], lu.solve(&row![3.0, 4.0]));
+    /// assert_eq!(row![2.0, -1.0], lu.solve(&row![3.0, -1.0]));
+    /// ```
+    pub fn solve(&self, b: &Vector<T>) -> Vector<T> {
+        let n = self.size();
+        assert_eq!(n, b.len());
+
+        // Forward substitution with the unit lower triangle, Ly = Pb, then back
+        // substitution with the upper triangle, Ux = y. Neither can fail, as the
+        // sizes match and U has no zero on its diagonal.
+        let pb = self.permutation.iter().map(|p| b[*p]).collect();
+        let y = triangular::forward_substitution(&self.lu, &pb, DiagonalKind::Unit)
+            .expect("L is square and unit");
+        triangular::back_substitution(&self.lu, &y, DiagonalKind::NonUnit)
+            .expect("U is non-singular")
+    }
+
+    /// Solve Aᵀx = b for x, reusing the factorization of A.
+    ///
+    /// As Aᵀ = UᵀLᵀP, this is a forward substitution with Uᵀ, a back
+    /// substitution with Lᵀ, then undoing the permutation.
+    ///
+    /// # Panics
+    /// Panics if `b` is not the same length as the order of A.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, row, matrix::Matrix, vector::Row, solver::lu::LuFactorization};
+    ///
+    /// let lu = LuFactorization::new(&mat![[0.0, 1.0], [2.0, 3.0]]).unwrap();
+    ///
+    /// // [0 2; 1 3] x = [4, 7]
+    /// assert_eq!(row![1.0, 2.0], lu.solve_transpose(&row![4.0, 7.0]));
+    /// ```
+    pub fn solve_transpose(&self, b: &Vector<T>) -> Vector<T> {
+        let n = self.size();
+        assert_eq!(n, b.len());
+
+        // Forward substitution with the lower triangle Uᵀ, Uᵀz = b, then back
+        // substitution with the unit upper triangle Lᵀ, Lᵀw = z, each reading
+        // the packed factors in place.
+        let z = triangular::forward_substitution_transpose(&self.lu, b, DiagonalKind::NonUnit)
+            .expect("U is non-singular");
+        let w = triangular::back_substitution_transpose(&self.lu, &z, DiagonalKind::Unit)
+            .expect("L is square and unit");
+
+        // Pᵀw, putting row i of PA back as row permutation()[i] of A
+        let mut x = w.clone();
+        for (i, &p) in self.permutation.iter().enumerate() {
+            x[p] = w[i];
+        }
+        x
+    }
+
+    /// Solve AX = B for X, treating each column of `b` as its own right-hand side.
+    ///
+    /// # Panics
+    /// Panics if `b` does not have as many rows as the order of A.
+    pub fn solve_matrix(&self, b: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.size(), b.rows);
+
+        let pb = Matrix::from_fn(b.rows, b.cols, |i, j| b[self.permutation[i]][j]);
+        let y = triangular::forward_substitution_matrix(&self.lu, &pb, DiagonalKind::Unit)
+            .expect("L is square and unit");
+        triangular::back_substitution_matrix(&self.lu, &y, DiagonalKind::NonUnit)
+            .expect("U is non-singular")
+    }
+
+    /// The determinant of A, read off of the diagonal of U.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix, solver::lu::LuFactorization};
+    ///
+    /// let a = mat![[0.0, 2.0], [3.0, 1.0]];
+    /// let lu = LuFactorization::new(&a).unwrap();
+    ///
+    /// assert_eq!(-6.0, lu.determinant());
+    /// ```
+    pub fn determinant(&self) -> T {
+        let det = (0..self.size()).fold(T::one(), |acc, i| acc * self.lu[i][i]);
+        if self.swaps.is_multiple_of(2) {
+            det
+        } else {
+            -det
+        }
+    }
+
+    /// Computes A⁻¹ by solving against each column of the identity.
+    pub fn inverse(&self) -> Matrix<T> {
+        self.solve_matrix(&Matrix::identity(self.size()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mat, row, vector::Row};
+
+    #[test]
+    fn test_plu_reconstructs_a() {
+        let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 10.0]];
+        let lu = LuFactorization::new(&a).unwrap();
+
+        let lu_product = &lu.l() * &lu.u();
+        for (i, p) in lu.permutation().iter().enumerate() {
+            for j in 0..a.cols {
+                assert!((lu_product[i][j] - a[*p][j]).abs() < 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_solve_many_rhs() {
+        let a = mat![[2.0, 1.0, 1.0], [4.0, -6.0, 0.0], [-2.0, 7.0, 2.0]];
+        let lu = LuFactorization::new(&a).unwrap();
+
+        assert_eq!(row![1.0, 1.0, 2.0], lu.solve(&row![5.0, -2.0, 9.0]));
+
+        let x = lu.solve_matrix(&mat![[5.0, 4.0], [-2.0, -2.0], [9.0, 7.0]]);
+        assert_eq!(mat![[1.0, 1.0], [1.0, 1.0], [2.0, 1.0]], x);
+    }
+
+    #[test]
+    fn test_pivots_on_magnitude() {
+        let a = mat![[1.0, 1.0], [-4.0, 2.0]];
+        let lu = LuFactorization::new(&a).unwrap();
+        assert_eq!(&[1, 0], lu.permutation());
+        assert_eq!(6.0, lu.determinant());
+    }
+
+    #[test]
+    fn test_inverse() {
+        let a = mat![[4.0, 7.0], [2.0, 6.0]];
+        let inv = LuFactorization::new(&a).unwrap().inverse();
+        let identity = &a * &inv;
+        assert!((identity[0][0] - 1.0).abs() < 1e-12);
+        assert!(identity[0][1].abs() < 1e-12);
+        assert!(identity[1][0].abs() < 1e-12);
+        assert!((identity[1][1] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_singular_and_non_square() {
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 1 }),
+            LuFactorization::new(&mat![[1.0, 2.0], [2.0, 4.0]])
+        );
+        assert_eq!(
+            Err(LinalgError::NotSquare { rows: 2, cols: 3 }),
+            LuFactorization::new(&mat![[1.0, 2.0, 3.0], [2.0, 4.0, 5.0]])
+        );
+    }
+
+    #[test]
+    fn test_pivot_lost_in_rounding_is_singular() {
+        // Rank 2, but the last pivot comes out as rounding error, not zero.
+        let a = mat![[1.0, 2.0, 3.0], [4.0, 5.0, 6.0], [7.0, 8.0, 9.0]];
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 2 }),
+            LuFactorization::new(&a)
+        );
+        assert_eq!(Ok(0.0), a.determinant());
+    }
+}
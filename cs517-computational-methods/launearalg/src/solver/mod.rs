@@ -1 +1,12 @@
+pub mod bareiss;
+pub mod cholesky;
+pub mod eigen;
 pub mod gauss;
+pub mod gram_schmidt;
+pub mod iterative;
+pub mod lu;
+pub mod qr;
+pub mod refinement;
+pub mod svd;
+pub mod trace;
+pub mod triangular;
@@ -0,0 +1,268 @@
+use crate::{
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    vector::Vector,
+};
+use num_traits::{Float, NumAssignOps};
+
+/// The decomposition A = QR of an m x n `Matrix` A with m ≥ n, computed by
+/// Householder reflections.
+///
+/// Q is never formed while decomposing; the reflectors are kept instead, and
+/// applied to right-hand sides on demand. The explicit factors are available
+/// either in full (Q is m x m, R is m x n), or thin (Q is m x n, R is n x n).
+#[derive(Debug, PartialEq, Clone)]
+pub struct QrFactorization<T> {
+    r: Matrix<T>,
+    // Unit Householder vectors, the k-th acting on rows k.. of its operand.
+    reflectors: Vec<Vec<T>>,
+}
+
+impl<T> QrFactorization<T>
+where
+    T: Float + NumAssignOps,
+{
+    /// Decompose the `Matrix` `a`.
+    ///
+    /// Returns an error if `a` has fewer rows than columns, or contains a NaN or
+    /// infinite entry.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix, solver::qr::QrFactorization};
+    ///
+    /// let a: Matrix<f64> = mat![[3.0, 1.0], [4.0, 2.0]];
+    /// let qr = QrFactorization::new(&a).unwrap();
+    ///
+    /// let r = qr.r();
+    /// assert!((r[0][0].abs() - 5.0).abs() < 1e-12);
+    /// assert!(r[1][0].abs() < 1e-12);
+    /// ```
+    pub fn new(a: &Matrix<T>) -> Result<Self> {
+        if a.rows < a.cols {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (a.cols, a.cols),
+                found: (a.rows, a.cols),
+            });
+        }
+        a.check_finite()?;
+
+        let (m, n) = (a.rows, a.cols);
+        let mut r = a.clone();
+        let mut reflectors = Vec::with_capacity(n);
+
+        for k in 0..n.min(m - 1) {
+            let mut v: Vec<T> = (k..m).map(|i| r[i][k]).collect();
+            let norm_x = norm(&v);
+
+            // Reflect x onto -sign(x₀)‖x‖e₀ to avoid cancellation in v₀.
+            let alpha = if v[0] < T::zero() { norm_x } else { -norm_x };
+            v[0] -= alpha;
+
+            let norm_v = norm(&v);
+            if norm_v == T::zero() {
+                reflectors.push(v);
+                continue;
+            }
+            v.iter_mut().for_each(|x| *x /= norm_v);
+
+            reflect(&v, &mut r, k, k);
+            reflectors.push(v);
+        }
+
+        Ok(QrFactorization { r, reflectors })
+    }
+
+    /// The full m x n upper triangular factor R.
+    pub fn r(&self) -> Matrix<T> {
+        let mut r = self.r.clone();
+        for i in 0..r.rows {
+            for j in 0..i.min(r.cols) {
+                r[i][j] = T::zero();
+            }
+        }
+        r
+    }
+
+    /// The thin n x n upper triangular factor R.
+    pub fn thin_r(&self) -> Matrix<T> {
+        let n = self.r.cols;
+        let mut r = Matrix::new(n, n);
+        for i in 0..n {
+            r[i][i..].copy_from_slice(&self.r[i][i..]);
+        }
+        r
+    }
+
+    /// The full m x m orthogonal factor Q.
+    pub fn q(&self) -> Matrix<T> {
+        self.q_columns(self.r.rows)
+    }
+
+    /// The thin m x n factor Q, whose columns are an orthonormal basis for
+    /// the column space of A.
+    pub fn thin_q(&self) -> Matrix<T> {
+        self.q_columns(self.r.cols)
+    }
+
+    /// Computes Qᵀb.
+    ///
+    /// # Panics
+    /// Panics if `b` does not have as many entries as A has rows.
+    pub fn qt_mul(&self, b: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.r.rows, b.len());
+
+        let mut y = Matrix::from(b.iter().map(|x| vec![*x]).collect::<Vec<Vec<T>>>());
+        for (k, v) in self.reflectors.iter().enumerate() {
+            reflect(v, &mut y, k, 0);
+        }
+        y.col(0)
+    }
+
+    /// Finds the x minimizing ‖Ax - b‖₂ by solving Rx = Qᵀb.
+    ///
+    /// Returns `LinalgError::Singular` if R has a zero on its diagonal, that is if
+    /// the columns of A are linearly dependent.
+    ///
+    /// # Panics
+    /// Panics if `b` does not have as many entries as A has rows.
+    pub fn solve_least_squares(&self, b: &Vector<T>) -> Result<Vector<T>> {
+        let n = self.r.cols;
+        let y = self.qt_mul(b);
+
+        let scale = (0..n).fold(T::zero(), |acc, i| acc.max(self.r[i][i].abs()));
+        let tolerance = scale * T::epsilon() * T::from(self.r.rows).unwrap();
+
+        let mut x: Vec<T> = (0..n).map(|i| y[i]).collect();
+        for i in (0..n).rev() {
+            if self.r[i][i].abs() <= tolerance {
+                return Err(LinalgError::Singular { pivot: i });
+            }
+            for j in i + 1..n {
+                let scaled = self.r[i][j] * x[j];
+                x[i] -= scaled;
+            }
+            x[i] /= self.r[i][i];
+        }
+
+        Ok(Vector::from(x))
+    }
+
+    fn q_columns(&self, cols: usize) -> Matrix<T> {
+        let m = self.r.rows;
+        let mut q = Matrix::new(m, cols);
+        for i in 0..cols {
+            q[i][i] = T::one();
+        }
+        for (k, v) in self.reflectors.iter().enumerate().rev() {
+            reflect(v, &mut q, k, 0);
+        }
+        q
+    }
+}
+
+/// Finds the x minimizing ‖Ax - b‖₂ via a Householder QR decomposition of A.
+///
+/// This avoids forming the normal equations XᵀX, whose condition number is
+/// the square of that of A, and so stays accurate for much worse conditioned
+/// design matrices.
+///
+/// Returns an error if A has fewer rows than columns, or linearly dependent columns.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, row, matrix::Matrix, vector::Row, solver::qr::least_squares_qr};
+///
+/// // Fit y = c₀ + c₁x through (0, 1), (1, 3), (2, 5)
+/// let a: Matrix<f64> = mat![[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]];
+/// let x = least_squares_qr(&a, &row![1.0, 3.0, 5.0]).unwrap();
+///
+/// assert!((x[0] - 1.0).abs() < 1e-12);
+/// assert!((x[1] - 2.0).abs() < 1e-12);
+/// ```
+pub fn least_squares_qr<T>(a: &Matrix<T>, b: &Vector<T>) -> Result<Vector<T>>
+where
+    T: Float + NumAssignOps,
+{
+    QrFactorization::new(a)?.solve_least_squares(b)
+}
+
+fn norm<T: Float>(v: &[T]) -> T {
+    v.iter().fold(T::zero(), |acc, x| acc.hypot(*x))
+}
+
+// Applies I - 2vvᵀ to rows `offset..` and columns `first_col..` of `a`.
+fn reflect<T>(v: &[T], a: &mut Matrix<T>, offset: usize, first_col: usize)
+where
+    T: Float + NumAssignOps,
+{
+    let two = T::one() + T::one();
+    for j in first_col..a.cols {
+        let dot = v
+            .iter()
+            .enumerate()
+            .fold(T::zero(), |acc, (i, vi)| acc + *vi * a[offset + i][j]);
+        for (i, vi) in v.iter().enumerate() {
+            a[offset + i][j] -= two * *vi * dot;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mat, row, test_util::assert_matrix_close, traits::Transpose, vector::Row};
+
+    #[test]
+    fn test_full_qr_reconstructs_a() {
+        let a = mat![[12.0, -51.0, 4.0], [6.0, 167.0, -68.0], [-4.0, 24.0, -41.0]];
+        let qr = QrFactorization::new(&a).unwrap();
+        let q = qr.q();
+
+        assert_matrix_close(&a, &(&q * &qr.r()));
+        assert_matrix_close(
+            &mat![[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+            &(&q.transpose() * &q),
+        );
+    }
+
+    #[test]
+    fn test_thin_qr_reconstructs_a() {
+        let a = mat![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0], [7.0, 8.0]];
+        let qr = QrFactorization::new(&a).unwrap();
+        let q = qr.thin_q();
+
+        assert_eq!((4, 2), (q.rows, q.cols));
+        assert_matrix_close(&a, &(&q * &qr.thin_r()));
+        assert_matrix_close(&mat![[1.0, 0.0], [0.0, 1.0]], &(&q.transpose() * &q));
+    }
+
+    #[test]
+    fn test_least_squares_on_large_x() {
+        // y = 80 - 0.0002x sampled every 30s over a long capture, where the
+        // normal equations lose most of their significant digits.
+        let xs: Vec<f64> = (0..2000).map(|i| 30_000.0 + 30.0 * i as f64).collect();
+        let a = Matrix::from(xs.iter().map(|x| vec![1.0, *x]).collect::<Vec<Vec<f64>>>());
+        let b = Vector::from(xs.iter().map(|x| 80.0 - 0.0002 * x).collect::<Vec<f64>>());
+
+        let w = least_squares_qr(&a, &b).unwrap();
+        assert!((w[0] - 80.0).abs() < 1e-9);
+        assert!((w[1] + 0.0002).abs() < 1e-13);
+    }
+
+    #[test]
+    fn test_rank_deficient_and_wide() {
+        let dependent = mat![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 1 }),
+            least_squares_qr(&dependent, &row![1.0, 2.0, 3.0])
+        );
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (3, 3),
+                found: (1, 3)
+            }),
+            QrFactorization::new(&mat![[1.0, 2.0, 3.0]])
+        );
+    }
+}
@@ -0,0 +1,480 @@
+//! Solving Ax = b with a measure of how far to trust the answer, and
+//! iterative refinement to improve it.
+//!
+//! Refinement reuses the LU factorization of A: each step solves Ad = r for
+//! the correction d to x from the residual r = b - Ax, so costs O(n²) rather
+//! than another O(n³) elimination. A `TridiagonalMatrix` is reported on by
+//! [`solve_tridiagonal`](fn.solve_tridiagonal.html) in O(n) instead.
+use crate::{
+    banded::TridiagonalMatrix,
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    solver::lu::LuFactorization,
+    vector::Vector,
+};
+use num_traits::{Float, NumAssignOps, Signed};
+use std::iter;
+
+/// Whether, and how far, to refine a solution.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct RefinementSettings<T> {
+    /// Refine at most this many times. 0 reports on the unrefined solution.
+    pub max_iterations: usize,
+    /// Stop refining once the backward error is at or below this.
+    pub tolerance: T,
+    /// Accumulate residuals with compensated dot products, as accurately as
+    /// if in twice the working precision. Without it, refinement only fixes
+    /// errors from an unstable factorization, not from ill-conditioning.
+    pub extended_precision: bool,
+}
+
+impl<T> Default for RefinementSettings<T>
+where
+    T: Float,
+{
+    /// No refinement, with a tolerance of ε for when it is turned on.
+    fn default() -> Self {
+        RefinementSettings {
+            max_iterations: 0,
+            tolerance: T::epsilon(),
+            extended_precision: false,
+        }
+    }
+}
+
+/// A solution to Ax = b, with diagnostics of its accuracy.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SolveReport<T> {
+    pub solution: Vector<T>,
+    /// The residual b - Ax of the solution.
+    pub residual: Vector<T>,
+    /// The normwise relative backward error ‖b - Ax‖∞ / (‖A‖∞‖x‖∞ + ‖b‖∞): the
+    /// smallest relative change to A and b of which x is the exact solution.
+    /// Near ε means the solve was as good as the data allows.
+    pub backward_error: T,
+    /// The pivot growth factor max|uᵢⱼ| / max|aᵢⱼ| of the factorization A = LU
+    /// the solve used. Large growth means elimination itself lost accuracy.
+    pub growth_factor: T,
+    /// An estimate of the condition number κ₁(A) = ‖A‖₁‖A⁻¹‖₁, by Hager's
+    /// method. The forward error is roughly this times the backward error.
+    pub condition_estimate: T,
+    /// The number of refinement steps taken.
+    pub refinement_steps: usize,
+}
+
+/// Solves Ax = b by LU decomposition with partial pivoting, refining the
+/// solution as `settings` allows, and reports on its accuracy.
+///
+/// Returns an error if `a` is not square, `b` has the wrong length, either has
+/// a NaN or infinite entry, or A is singular.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, row, vector::Row, solver::refinement::{self, RefinementSettings}};
+///
+/// let a = mat![[4.0, 1.0], [1.0, 3.0]];
+/// let report = refinement::solve(&a, &row![1.0, 2.0], &RefinementSettings::default()).unwrap();
+///
+/// assert!(report.backward_error < 1e-15);
+/// assert!(report.condition_estimate < 3.0);
+/// ```
+pub fn solve<T>(
+    a: &Matrix<T>,
+    b: &Vector<T>,
+    settings: &RefinementSettings<T>,
+) -> Result<SolveReport<T>>
+where
+    T: Float + Signed + NumAssignOps,
+{
+    if b.len() != a.rows {
+        return Err(LinalgError::DimensionMismatch {
+            expected: (a.rows, 1),
+            found: (b.len(), 1),
+        });
+    }
+    check_finite(b)?;
+    let lu = LuFactorization::new(a)?;
+
+    let residual_of = |x: &Vector<T>| {
+        a.rows()
+            .zip(b.iter())
+            .map(|(row, &bi)| {
+                let terms = row.iter().copied().zip(x.iter().copied());
+                residual_entry(terms, bi, settings.extended_precision)
+            })
+            .collect()
+    };
+    let (solution, residual, refinement_steps) = refine(
+        b,
+        |r| Ok(lu.solve(r)),
+        residual_of,
+        |x, r| backward_error(a.norm_inf(), x, b, r),
+        settings,
+    )?;
+
+    let condition_estimate =
+        a.norm_1() * inverse_norm_1(a.rows, |x| Ok(lu.solve(x)), |x| Ok(lu.solve_transpose(x)))?;
+    Ok(SolveReport {
+        backward_error: backward_error(a.norm_inf(), &solution, b, &residual),
+        growth_factor: max_abs(lu.u().iter().flatten().copied())
+            / max_abs(a.iter().flatten().copied()),
+        condition_estimate,
+        solution,
+        residual,
+        refinement_steps,
+    })
+}
+
+/// Solves Ax = b for a `TridiagonalMatrix` A by the Thomas algorithm, refining
+/// the solution as `settings` allows, and reports on its accuracy, all in O(n).
+///
+/// The Thomas algorithm does not pivot, so the growth factor is of the
+/// factorization A = LU it computes without pivoting, and may be large where
+/// A is not diagonally dominant.
+///
+/// Returns an error if `b` has the wrong length, either has a NaN or infinite
+/// entry, or a zero pivot is met.
+///
+/// # Example
+/// ```
+/// use launearalg::{banded::TridiagonalMatrix, row, vector::Row, solver::refinement::{self, RefinementSettings}};
+///
+/// let a = TridiagonalMatrix::from_diagonals(vec![1.0, 1.0], vec![4.0, 4.0, 4.0], vec![1.0, 1.0]).unwrap();
+/// let report = refinement::solve_tridiagonal(&a, &row![5.0, 6.0, 5.0], &RefinementSettings::default()).unwrap();
+///
+/// assert_eq!(row![1.0, 1.0, 1.0], report.solution);
+/// assert!(report.condition_estimate < 3.0);
+/// ```
+pub fn solve_tridiagonal<T>(
+    a: &TridiagonalMatrix<T>,
+    b: &Vector<T>,
+    settings: &RefinementSettings<T>,
+) -> Result<SolveReport<T>>
+where
+    T: Float + NumAssignOps,
+{
+    let n = a.size();
+    if b.len() != n {
+        return Err(LinalgError::DimensionMismatch {
+            expected: (n, 1),
+            found: (b.len(), 1),
+        });
+    }
+    check_finite(b)?;
+    // Row i of A has entries in columns i - 1 to i + 1.
+    let band = |i: usize| i.saturating_sub(1)..(i + 2).min(n);
+    let entries = || (0..n).flat_map(move |i| band(i).map(move |j| (i, j)));
+    if let Some((row, col)) = entries().find(|&(i, j)| !a.get(i, j).is_finite()) {
+        return Err(LinalgError::NonFinite { row, col });
+    }
+
+    let residual_of = |x: &Vector<T>| {
+        (0..n)
+            .map(|i| {
+                let terms = band(i).map(|j| (a.get(i, j), x[j]));
+                residual_entry(terms, b[i], settings.extended_precision)
+            })
+            .collect()
+    };
+    // The largest absolute row and column sums.
+    let norm_inf = (0..n)
+        .map(|i| band(i).fold(T::zero(), |acc, j| acc + a.get(i, j).abs()))
+        .fold(T::zero(), T::max);
+    let norm_1 = (0..n)
+        .map(|j| band(j).fold(T::zero(), |acc, i| acc + a.get(i, j).abs()))
+        .fold(T::zero(), T::max);
+
+    let (solution, residual, refinement_steps) = refine(
+        b,
+        |r| a.solve(r),
+        residual_of,
+        |x, r| backward_error(norm_inf, x, b, r),
+        settings,
+    )?;
+
+    // Aᵀ is tridiagonal too, with the sub- and super-diagonals exchanged.
+    let transpose = TridiagonalMatrix::from_diagonals(
+        a.upper().to_vec(),
+        a.diagonal().to_vec(),
+        a.lower().to_vec(),
+    )?;
+    let condition_estimate = norm_1 * inverse_norm_1(n, |x| a.solve(x), |x| transpose.solve(x))?;
+    // Without pivoting, U has the pivots on its diagonal and A's
+    // super-diagonal above it.
+    let u = thomas_pivots(a)
+        .into_iter()
+        .chain(a.upper().iter().copied());
+    Ok(SolveReport {
+        backward_error: backward_error(norm_inf, &solution, b, &residual),
+        growth_factor: max_abs(u) / max_abs(entries().map(|(i, j)| a.get(i, j))),
+        condition_estimate,
+        solution,
+        residual,
+        refinement_steps,
+    })
+}
+
+fn check_finite<T: Float>(b: &Vector<T>) -> Result<()> {
+    match b.iter().position(|x| !x.is_finite()) {
+        Some(i) => Err(LinalgError::NonFinite { row: i, col: 0 }),
+        None => Ok(()),
+    }
+}
+
+// Solves with `solve`, then refines the solution as `settings` allows, giving
+// the solution, its residual and the number of refinement steps taken.
+fn refine<T, S, R, E>(
+    b: &Vector<T>,
+    solve: S,
+    residual_of: R,
+    backward_error: E,
+    settings: &RefinementSettings<T>,
+) -> Result<(Vector<T>, Vector<T>, usize)>
+where
+    T: Float + NumAssignOps,
+    S: Fn(&Vector<T>) -> Result<Vector<T>>,
+    R: Fn(&Vector<T>) -> Vector<T>,
+    E: Fn(&Vector<T>, &Vector<T>) -> T,
+{
+    let mut solution = solve(b)?;
+    let mut residual = residual_of(&solution);
+    let mut refinement_steps = 0;
+    while refinement_steps < settings.max_iterations
+        && backward_error(&solution, &residual) > settings.tolerance
+    {
+        solution += &solve(&residual)?;
+        residual = residual_of(&solution);
+        refinement_steps += 1;
+    }
+    Ok((solution, residual, refinement_steps))
+}
+
+// b - a·x for one row, given the pairs (aⱼ, xⱼ).
+fn residual_entry<T, I>(terms: I, bi: T, extended: bool) -> T
+where
+    T: Float,
+    I: Iterator<Item = (T, T)>,
+{
+    if extended {
+        // The compensated dot product of [a, 1]·[-x, b].
+        dot2(
+            terms
+                .map(|(a, x)| (a, -x))
+                .chain(iter::once((T::one(), bi))),
+        )
+    } else {
+        terms.fold(bi, |acc, (a, x)| acc - a * x)
+    }
+}
+
+// Ogita, Rump and Oishi's Dot2: a dot product as accurate as if computed in
+// twice the working precision, then rounded.
+fn dot2<T, I>(pairs: I) -> T
+where
+    T: Float,
+    I: Iterator<Item = (T, T)>,
+{
+    let (sum, compensation) = pairs.fold((T::zero(), T::zero()), |(s, c), (x, y)| {
+        let p = x * y;
+        let product_error = x.mul_add(y, -p);
+        // Knuth's TwoSum, s + p = t + sum_error exactly.
+        let t = s + p;
+        let z = t - s;
+        let sum_error = (s - (t - z)) + (p - z);
+        (t, c + product_error + sum_error)
+    });
+    sum + compensation
+}
+
+fn backward_error<T>(a_norm_inf: T, x: &Vector<T>, b: &Vector<T>, r: &Vector<T>) -> T
+where
+    T: Float,
+{
+    let scale = a_norm_inf * x.norm_inf() + b.norm_inf();
+    if scale.is_zero() {
+        T::zero()
+    } else {
+        r.norm_inf() / scale
+    }
+}
+
+fn max_abs<T, I>(xs: I) -> T
+where
+    T: Float,
+    I: Iterator<Item = T>,
+{
+    xs.fold(T::zero(), |acc, x| acc.max(x.abs()))
+}
+
+// The pivots pᵢ = dᵢ - lᵢ₋₁uᵢ₋₁ / pᵢ₋₁ which the Thomas algorithm divides by.
+fn thomas_pivots<T>(a: &TridiagonalMatrix<T>) -> Vec<T>
+where
+    T: Float + NumAssignOps,
+{
+    let mut pivots: Vec<T> = Vec::with_capacity(a.size());
+    for (i, &d) in a.diagonal().iter().enumerate() {
+        let pivot = match pivots.last() {
+            Some(&previous) => d - a.lower()[i - 1] * a.upper()[i - 1] / previous,
+            None => d,
+        };
+        pivots.push(pivot);
+    }
+    pivots
+}
+
+// Hager's estimate of ‖A⁻¹‖₁ for an n x n A, which hill-climbs over ‖A⁻¹x‖₁
+// on the unit ball of the 1-norm, using a solve with A and one with Aᵀ a step.
+fn inverse_norm_1<T, S, U>(n: usize, solve: S, solve_transpose: U) -> Result<T>
+where
+    T: Float,
+    S: Fn(&Vector<T>) -> Result<Vector<T>>,
+    U: Fn(&Vector<T>) -> Result<Vector<T>>,
+{
+    let mut x = Vector::from(vec![T::one() / T::from(n).unwrap(); n]);
+    let mut estimate = T::zero();
+    for iteration in 0..5 {
+        let y = solve(&x)?;
+        estimate = y.norm_1();
+
+        let signs = y
+            .iter()
+            .map(|yi| if *yi < T::zero() { -T::one() } else { T::one() })
+            .collect();
+        let z = solve_transpose(&signs)?;
+        let (j, z_max) =
+            z.iter()
+                .map(|zi| zi.abs())
+                .enumerate()
+                .fold(
+                    (0, T::zero()),
+                    |best, (i, zi)| if zi > best.1 { (i, zi) } else { best },
+                );
+        if iteration > 0 && z_max <= z.dot(&x) {
+            break;
+        }
+        x = Vector::from(vec![T::zero(); n]);
+        x[j] = T::one();
+    }
+    Ok(estimate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mat, solver::gauss, test_util::hilbert};
+
+    #[test]
+    fn test_condition_estimate_matches_exact_for_small_matrix() {
+        let a = mat![[1.0, 2.0], [3.0, 4.0]];
+        let exact = a.norm_1() * gauss::inverse(&a).unwrap().norm_1();
+        let report = solve(
+            &a,
+            &Vector::from(vec![1.0, 1.0]),
+            &RefinementSettings::default(),
+        )
+        .unwrap();
+        assert!((report.condition_estimate - exact).abs() < 1e-12 * exact);
+
+        let hilbert_report = solve(
+            &hilbert(8, 8),
+            &Vector::from(vec![1.0; 8]),
+            &RefinementSettings::default(),
+        )
+        .unwrap();
+        // κ₁ of the 8 x 8 Hilbert matrix is about 3.4e10.
+        assert!(hilbert_report.condition_estimate > 1e10);
+    }
+
+    #[test]
+    fn test_growth_factor_of_wilkinson_like_matrix() {
+        // No rows are swapped, and each step grows the last column by half.
+        let n = 10;
+        let a = Matrix::from_fn(n, n, |i, j| match () {
+            _ if j == n - 1 || i == j => 1.0,
+            _ if i > j => -0.5,
+            _ => 0.0,
+        });
+        let report = solve(
+            &a,
+            &Vector::from(vec![1.0; n]),
+            &RefinementSettings::default(),
+        )
+        .unwrap();
+        assert_eq!(1.5f64.powi(n as i32 - 1), report.growth_factor);
+    }
+
+    #[test]
+    fn test_refinement_reduces_backward_error() {
+        // κ of the 5 x 5 Hilbert matrix is about 5e5, ill-conditioned in f32
+        // but, unlike larger ones, not singular to within rounding.
+        let a = Matrix::from_fn(5, 5, |i, j| 1.0 / (i + j + 1) as f32);
+        let x = Vector::from(vec![1f32; 5]);
+        let b = &a * &x;
+
+        let plain = solve(&a, &b, &RefinementSettings::default()).unwrap();
+        let refined = solve(
+            &a,
+            &b,
+            &RefinementSettings {
+                max_iterations: 5,
+                extended_precision: true,
+                ..RefinementSettings::default()
+            },
+        )
+        .unwrap();
+        assert!(refined.backward_error <= plain.backward_error);
+        assert!(refined.backward_error <= f32::EPSILON);
+        assert_eq!(0, plain.refinement_steps);
+    }
+
+    #[test]
+    fn test_tridiagonal_report_agrees_with_dense() {
+        let a = TridiagonalMatrix::from_diagonals(
+            vec![1.0, -2.0, 0.5, 1.0],
+            vec![4.0, 5.0, 3.0, 6.0, 2.0],
+            vec![2.0, 1.0, -1.0, 0.5],
+        )
+        .unwrap();
+        let b = Vector::from(vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+        let settings = RefinementSettings {
+            max_iterations: 2,
+            extended_precision: true,
+            ..RefinementSettings::default()
+        };
+
+        let banded = solve_tridiagonal(&a, &b, &settings).unwrap();
+        let dense = solve(&a.to_matrix(), &b, &settings).unwrap();
+        assert!((&banded.solution - &dense.solution).norm_inf() < 1e-14);
+        assert!(banded.backward_error <= f64::EPSILON);
+        // Diagonally dominant, so partial pivoting swaps no rows either.
+        assert!((banded.growth_factor - dense.growth_factor).abs() < 1e-14);
+        let exact = a.to_matrix().norm_1() * gauss::inverse(&a.to_matrix()).unwrap().norm_1();
+        assert!((banded.condition_estimate - exact).abs() < 1e-12 * exact);
+    }
+
+    #[test]
+    fn test_tridiagonal_rejects_non_finite_entries() {
+        let a =
+            TridiagonalMatrix::from_diagonals(vec![1.0], vec![2.0, 2.0], vec![f64::NAN]).unwrap();
+        assert_eq!(
+            Err(LinalgError::NonFinite { row: 0, col: 1 }),
+            solve_tridiagonal(
+                &a,
+                &Vector::from(vec![1.0, 1.0]),
+                &RefinementSettings::default()
+            )
+        );
+    }
+
+    #[test]
+    fn test_dot2_is_exact_where_naive_is_not() {
+        let xs = [1e16, 1.0, -1e16];
+        let ys = [1.0, 1.0, 1.0];
+        let naive = xs
+            .iter()
+            .zip(ys.iter())
+            .fold(0.0, |acc, (x, y)| acc + x * y);
+        assert_eq!(0.0, naive);
+        assert_eq!(1.0, dot2(xs.iter().copied().zip(ys.iter().copied())));
+    }
+}
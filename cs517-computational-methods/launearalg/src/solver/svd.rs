@@ -0,0 +1,364 @@
+use crate::{error::Result, matrix::Matrix, traits::Transpose, vector::Vector};
+use num_traits::{Float, NumAssignOps};
+
+// Sweeps over every column pair are quadratically convergent once close, so
+// well conditioned matrices need well under 10. This only guards against spinning.
+const MAX_SWEEPS: usize = 60;
+
+/// The thin singular value decomposition A = UΣVᵀ of an m x n `Matrix` A,
+/// computed by one-sided Jacobi rotations.
+///
+/// With k = min(m, n), U is m x k, Σ holds k singular values sorted from
+/// largest to smallest, and V is n x k. For zero singular values, the columns
+/// of U (or of V, when m < n and Aᵀ is decomposed instead) are left zeroed
+/// rather than completed to an orthonormal basis.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Svd<T> {
+    u: Matrix<T>,
+    singular_values: Vector<T>,
+    v: Matrix<T>,
+}
+
+impl<T> Svd<T>
+where
+    T: Float + NumAssignOps,
+{
+    /// Decompose the `Matrix` `a`.
+    ///
+    /// Returns an error if `a` contains a NaN or infinite entry.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix, solver::svd::Svd};
+    ///
+    /// let a: Matrix<f64> = mat![[3.0, 0.0], [0.0, -4.0]];
+    /// let svd = Svd::new(&a).unwrap();
+    ///
+    /// assert_eq!(4.0, svd.singular_values()[0]);
+    /// assert_eq!(3.0, svd.singular_values()[1]);
+    /// ```
+    pub fn new(a: &Matrix<T>) -> Result<Self> {
+        a.check_finite()?;
+        if a.rows < a.cols {
+            let svd = Svd::new(&a.transpose())?;
+            return Ok(Svd {
+                u: svd.v,
+                singular_values: svd.singular_values,
+                v: svd.u,
+            });
+        }
+
+        let n = a.cols;
+        let mut u = a.clone();
+        let mut v = Matrix::identity(n);
+
+        for _sweep in 0..MAX_SWEEPS {
+            let mut rotated = false;
+            for p in 0..n {
+                for q in p + 1..n {
+                    let (alpha, beta, gamma) = (0..u.rows).fold(
+                        (T::zero(), T::zero(), T::zero()),
+                        |(alpha, beta, gamma), i| {
+                            (
+                                alpha + u[i][p] * u[i][p],
+                                beta + u[i][q] * u[i][q],
+                                gamma + u[i][p] * u[i][q],
+                            )
+                        },
+                    );
+
+                    if gamma.abs() <= T::epsilon() * (alpha * beta).sqrt() {
+                        continue;
+                    }
+                    rotated = true;
+
+                    let zeta = (beta - alpha) / (gamma + gamma);
+                    let t = zeta.signum() / (zeta.abs() + (T::one() + zeta * zeta).sqrt());
+                    let c = T::one() / (T::one() + t * t).sqrt();
+                    let s = c * t;
+
+                    rotate_columns(&mut u, p, q, c, s);
+                    rotate_columns(&mut v, p, q, c, s);
+                }
+            }
+            if !rotated {
+                break;
+            }
+        }
+
+        let norms: Vec<T> = (0..n)
+            .map(|j| (0..u.rows).fold(T::zero(), |acc, i| acc.hypot(u[i][j])))
+            .collect();
+        let mut order: Vec<usize> = (0..n).collect();
+        order.sort_by(|x, y| norms[*y].partial_cmp(&norms[*x]).unwrap());
+
+        let mut sorted_u = Matrix::new(u.rows, n);
+        let mut sorted_v = Matrix::new(n, n);
+        for (k, j) in order.iter().enumerate() {
+            let sigma = norms[*j];
+            if sigma > T::zero() {
+                for i in 0..u.rows {
+                    sorted_u[i][k] = u[i][*j] / sigma;
+                }
+            }
+            for i in 0..n {
+                sorted_v[i][k] = v[i][*j];
+            }
+        }
+
+        Ok(Svd {
+            u: sorted_u,
+            singular_values: Vector::from(order.iter().map(|j| norms[*j]).collect::<Vec<T>>()),
+            v: sorted_v,
+        })
+    }
+
+    /// The left singular vectors, as the columns of an m x k `Matrix`.
+    pub fn u(&self) -> &Matrix<T> {
+        &self.u
+    }
+
+    /// The singular values, largest first.
+    pub fn singular_values(&self) -> &Vector<T> {
+        &self.singular_values
+    }
+
+    /// The right singular vectors, as the columns of an n x k `Matrix`.
+    pub fn v(&self) -> &Matrix<T> {
+        &self.v
+    }
+
+    /// The default cut-off below which a singular value is treated as zero:
+    /// max(m, n) · ε · σ₁.
+    pub fn default_tolerance(&self) -> T {
+        let size = self.u.rows.max(self.v.rows);
+        T::from(size).unwrap() * T::epsilon() * self.singular_values[0]
+    }
+
+    /// Number of singular values greater than `tol`.
+    pub fn rank(&self, tol: T) -> usize {
+        self.singular_values.iter().filter(|s| **s > tol).count()
+    }
+
+    /// The 2-norm condition number σ₁ / σₖ, infinite when A is rank-deficient.
+    pub fn cond(&self) -> T {
+        let smallest = self.singular_values[self.singular_values.len() - 1];
+        if smallest == T::zero() {
+            T::infinity()
+        } else {
+            self.singular_values[0] / smallest
+        }
+    }
+
+    /// The Moore-Penrose pseudo-inverse VΣ⁺Uᵀ, inverting only those singular
+    /// values greater than `tol`.
+    pub fn pinv(&self, tol: T) -> Matrix<T> {
+        let (m, n) = (self.u.rows, self.v.rows);
+        let mut pinv = Matrix::new(n, m);
+        for (k, sigma) in self.singular_values.iter().enumerate() {
+            if *sigma <= tol {
+                continue;
+            }
+            for i in 0..n {
+                let scaled = self.v[i][k] / *sigma;
+                for j in 0..m {
+                    pinv[i][j] += scaled * self.u[j][k];
+                }
+            }
+        }
+        pinv
+    }
+
+    /// Finds the x of smallest norm among those minimizing ‖Ax - b‖₂, ignoring
+    /// singular values at or below `tol`.
+    ///
+    /// Unlike the normal equations or QR, this has an answer even when the
+    /// columns of A are linearly dependent.
+    ///
+    /// # Panics
+    /// Panics if `b` does not have as many entries as A has rows.
+    pub fn solve_min_norm(&self, b: &Vector<T>, tol: T) -> Vector<T> {
+        let (m, n) = (self.u.rows, self.v.rows);
+        assert_eq!(m, b.len());
+
+        let mut x = vec![T::zero(); n];
+        for (k, sigma) in self.singular_values.iter().enumerate() {
+            if *sigma <= tol {
+                continue;
+            }
+            let coefficient = (0..m).fold(T::zero(), |acc, i| acc + self.u[i][k] * b[i]) / *sigma;
+            for (i, x) in x.iter_mut().enumerate() {
+                *x += coefficient * self.v[i][k];
+            }
+        }
+        Vector::from(x)
+    }
+}
+
+impl<T> Matrix<T>
+where
+    T: Float + NumAssignOps,
+{
+    /// Computes the singular value decomposition of the `Matrix`.
+    ///
+    /// Returns an error if the `Matrix` contains a NaN or infinite entry, as
+    /// do the methods below which are computed from it.
+    pub fn svd(&self) -> Result<Svd<T>> {
+        Svd::new(self)
+    }
+
+    /// Numerical rank, the number of singular values greater than `tol`.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let a: Matrix<f64> = mat![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+    /// assert_eq!(Ok(1), a.rank(1e-10));
+    /// ```
+    pub fn rank(&self, tol: T) -> Result<usize> {
+        Ok(self.svd()?.rank(tol))
+    }
+
+    /// The 2-norm, or spectral norm, which is the largest singular value σ₁.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let a: Matrix<f64> = mat![[3.0, 0.0], [4.0, 0.0]];
+    /// assert!((a.norm_2().unwrap() - 5.0).abs() < 1e-12);
+    /// ```
+    pub fn norm_2(&self) -> Result<T> {
+        Ok(self.svd()?.singular_values()[0])
+    }
+
+    /// The 2-norm condition number σ₁ / σₖ.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let a: Matrix<f64> = mat![[2.0, 0.0], [0.0, 0.5]];
+    /// assert!((a.cond().unwrap() - 4.0).abs() < 1e-12);
+    /// ```
+    pub fn cond(&self) -> Result<T> {
+        Ok(self.svd()?.cond())
+    }
+
+    /// The Moore-Penrose pseudo-inverse, treating singular values below the
+    /// [default tolerance](../solver/svd/struct.Svd.html#method.default_tolerance) as zero.
+    pub fn pinv(&self) -> Result<Matrix<T>> {
+        let svd = self.svd()?;
+        Ok(svd.pinv(svd.default_tolerance()))
+    }
+}
+
+/// Finds the minimum-norm x minimizing ‖Ax - b‖₂ via the SVD of A, treating
+/// singular values below the default tolerance as zero.
+///
+/// Returns an error if A contains a NaN or infinite entry.
+///
+/// # Panics
+/// Panics if `b` does not have as many entries as A has rows.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, row, matrix::Matrix, vector::Row, solver::svd::least_squares_svd};
+///
+/// // The columns are identical, so the weight is split evenly between them.
+/// let a: Matrix<f64> = mat![[1.0, 1.0], [2.0, 2.0]];
+/// let x = least_squares_svd(&a, &row![2.0, 4.0]).unwrap();
+///
+/// assert!((x[0] - 1.0).abs() < 1e-12);
+/// assert!((x[1] - 1.0).abs() < 1e-12);
+/// ```
+pub fn least_squares_svd<T>(a: &Matrix<T>, b: &Vector<T>) -> Result<Vector<T>>
+where
+    T: Float + NumAssignOps,
+{
+    let svd = Svd::new(a)?;
+    Ok(svd.solve_min_norm(b, svd.default_tolerance()))
+}
+
+fn rotate_columns<T>(a: &mut Matrix<T>, p: usize, q: usize, c: T, s: T)
+where
+    T: Float + NumAssignOps,
+{
+    for i in 0..a.rows {
+        let (ap, aq) = (a[i][p], a[i][q]);
+        a[i][p] = c * ap - s * aq;
+        a[i][q] = s * ap + c * aq;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{error::LinalgError, mat, row, test_util::assert_matrix_close, vector::Row};
+
+    fn reconstruct(svd: &Svd<f64>) -> Matrix<f64> {
+        let mut us = svd.u().clone();
+        for i in 0..us.rows {
+            for k in 0..us.cols {
+                us[i][k] *= svd.singular_values()[k];
+            }
+        }
+        &us * &svd.v().transpose()
+    }
+
+    #[test]
+    fn test_svd_reconstructs_tall_and_wide() {
+        let tall = mat![[1.0, 2.0], [3.0, 4.0], [5.0, 6.0]];
+        let wide = tall.transpose();
+
+        let tall_svd = Svd::new(&tall).unwrap();
+        let wide_svd = Svd::new(&wide).unwrap();
+
+        assert_matrix_close(&tall, &reconstruct(&tall_svd));
+        assert_matrix_close(&wide, &reconstruct(&wide_svd));
+        assert_eq!(tall_svd.singular_values(), wide_svd.singular_values());
+        assert!(tall_svd.singular_values()[0] > tall_svd.singular_values()[1]);
+    }
+
+    #[test]
+    fn test_rank_and_cond() {
+        let a = mat![[1.0, 0.0, 0.0], [0.0, 1e-3, 0.0], [0.0, 0.0, 0.0]];
+        assert_eq!(Ok(2), a.rank(1e-10));
+        assert_eq!(Ok(1), a.rank(1e-2));
+        assert_eq!(Ok(f64::INFINITY), a.cond());
+    }
+
+    #[test]
+    fn test_pinv_of_invertible_is_inverse() {
+        let a = mat![[4.0, 7.0], [2.0, 6.0]];
+        assert_matrix_close(&mat![[0.6, -0.7], [-0.2, 0.4]], &a.pinv().unwrap());
+    }
+
+    #[test]
+    fn test_pinv_penrose_conditions() {
+        let a = mat![[1.0, 2.0], [2.0, 4.0], [3.0, 6.0]];
+        let a_plus = a.pinv().unwrap();
+        assert_matrix_close(&a, &(&(&a * &a_plus) * &a));
+        assert_matrix_close(&a_plus, &(&(&a_plus * &a) * &a_plus));
+    }
+
+    #[test]
+    fn test_min_norm_least_squares_matches_full_rank_solution() {
+        let a = mat![[1.0, 0.0], [1.0, 1.0], [1.0, 2.0]];
+        let x = least_squares_svd(&a, &row![1.0, 3.0, 5.0]).unwrap();
+        assert!((x[0] - 1.0).abs() < 1e-12);
+        assert!((x[1] - 2.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_non_finite_entries_are_rejected() {
+        let a = mat![[f64::NAN, 1.0], [1.0, 1.0]];
+        assert_eq!(Err(LinalgError::NonFinite { row: 0, col: 0 }), Svd::new(&a));
+        assert!(mat![[1.0, f64::INFINITY]].rank(1e-10).is_err());
+        assert!(a.cond().is_err());
+        assert!(a.norm_2().is_err());
+        assert!(a.pinv().is_err());
+        assert!(least_squares_svd(&a, &row![1.0, 1.0]).is_err());
+    }
+}
@@ -0,0 +1,298 @@
+//! A record of each row operation gaussian elimination performs, made by
+//! [`gauss::solve_with_trace`](../gauss/fn.solve_with_trace.html).
+//!
+//! Rows and columns are numbered from 0 in the `Step`s, as everywhere else in
+//! the crate, but from 1 when rendered, as in a textbook.
+use crate::matrix::Matrix;
+use num_traits::Num;
+use std::fmt::{self, Display};
+
+/// One row or column operation on the augmented matrix [A | b].
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Step<T> {
+    /// Rows `a` and `b` were exchanged to bring up a pivot.
+    SwapRows { a: usize, b: usize },
+    /// Columns `a` and `b` of A were exchanged to bring across a pivot,
+    /// reordering the unknowns.
+    SwapCols { a: usize, b: usize },
+    /// Row `row` was divided through by its pivot, `divisor`.
+    Scale { row: usize, divisor: T },
+    /// `multiplier` times row `pivot_row` was subtracted from row `row`,
+    /// clearing the entry below the pivot.
+    Eliminate {
+        row: usize,
+        pivot_row: usize,
+        multiplier: T,
+    },
+    /// `multiplier` times row `pivot_row` was subtracted from row `row`,
+    /// clearing the entry above the pivot.
+    BackSubstitute {
+        row: usize,
+        pivot_row: usize,
+        multiplier: T,
+    },
+}
+
+/// A `Step`, and the augmented matrix just after it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TraceStep<T> {
+    pub step: Step<T>,
+    pub matrix: Matrix<T>,
+}
+
+/// Every step of an elimination, in order, from the augmented matrix it
+/// started with.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Trace<T> {
+    pub initial: Matrix<T>,
+    pub steps: Vec<TraceStep<T>>,
+}
+
+impl<T> Trace<T>
+where
+    T: Num + Copy,
+{
+    pub(crate) fn new(initial: Matrix<T>) -> Self {
+        Trace {
+            initial,
+            steps: Vec::new(),
+        }
+    }
+
+    pub(crate) fn record(&mut self, step: Step<T>, matrix: &Matrix<T>) {
+        self.steps.push(TraceStep {
+            step,
+            matrix: matrix.clone(),
+        });
+    }
+}
+
+impl<T> Trace<T>
+where
+    T: Num + Copy + Display,
+{
+    /// Render the trace as plain text, each step followed by the matrix it
+    /// left.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix, solver::gauss::{self, PivotStrategy}};
+    ///
+    /// let (_, trace) = gauss::solve_with_trace(mat![[1.0, 1.0, 3.0], [2.0, 4.0, 10.0]], PivotStrategy::Partial);
+    /// let text = trace.to_text();
+    ///
+    /// assert!(text.starts_with("Start\n[ 1  1 |  3 ]\n[ 2  4 | 10 ]\n"));
+    /// assert!(text.contains("R1 ↔ R2\n[ 2  4 | 10 ]\n[ 1  1 |  3 ]\n"));
+    /// ```
+    pub fn to_text(&self) -> String {
+        let mut text = format!("Start\n{}", text_matrix(&self.initial));
+        for step in &self.steps {
+            text += &format!("\n{}\n{}", step.step, text_matrix(&step.matrix));
+        }
+        text
+    }
+
+    /// Render the trace as a LaTeX `align*` environment, each step an arrow
+    /// labelled with its row operation.
+    pub fn to_latex(&self) -> String {
+        let mut latex = format!("\\begin{{align*}}\n& {}", latex_matrix(&self.initial));
+        for step in &self.steps {
+            latex += &format!(
+                " \\\\\n\\xrightarrow{{{}}} \\quad & {}",
+                latex_step(&step.step),
+                latex_matrix(&step.matrix)
+            );
+        }
+        latex + "\n\\end{align*}\n"
+    }
+}
+
+impl<T> Display for Step<T>
+where
+    T: Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> std::result::Result<(), fmt::Error> {
+        match self {
+            Step::SwapRows { a, b } => write!(f, "R{} ↔ R{}", a + 1, b + 1),
+            Step::SwapCols { a, b } => write!(f, "C{} ↔ C{}", a + 1, b + 1),
+            Step::Scale { row, divisor } => {
+                write!(f, "R{} ← R{} / {}", row + 1, row + 1, divisor)
+            }
+            Step::Eliminate {
+                row,
+                pivot_row,
+                multiplier,
+            }
+            | Step::BackSubstitute {
+                row,
+                pivot_row,
+                multiplier,
+            } => write!(
+                f,
+                "R{} ← R{} − ({})R{}",
+                row + 1,
+                row + 1,
+                multiplier,
+                pivot_row + 1
+            ),
+        }
+    }
+}
+
+fn latex_step<T: Display>(step: &Step<T>) -> String {
+    match step {
+        Step::SwapRows { a, b } => format!("R_{{{}}} \\leftrightarrow R_{{{}}}", a + 1, b + 1),
+        Step::SwapCols { a, b } => format!("C_{{{}}} \\leftrightarrow C_{{{}}}", a + 1, b + 1),
+        Step::Scale { row, divisor } => format!("R_{{{}}} / {}", row + 1, divisor),
+        Step::Eliminate {
+            row,
+            pivot_row,
+            multiplier,
+        }
+        | Step::BackSubstitute {
+            row,
+            pivot_row,
+            multiplier,
+        } => format!(
+            "R_{{{}}} - ({}) R_{{{}}}",
+            row + 1,
+            multiplier,
+            pivot_row + 1
+        ),
+    }
+}
+
+// The entries of `m` as strings, each right-aligned to the width of its column.
+fn aligned<T>(m: &Matrix<T>) -> Vec<Vec<String>>
+where
+    T: Num + Copy + Display,
+{
+    let entries = m
+        .rows()
+        .map(|row| row.iter().map(|x| x.to_string()).collect::<Vec<_>>())
+        .collect::<Vec<_>>();
+    let widths = (0..m.cols)
+        .map(|j| {
+            entries
+                .iter()
+                .map(|row| row[j].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect::<Vec<_>>();
+    entries
+        .into_iter()
+        .map(|row| {
+            row.into_iter()
+                .zip(widths.iter())
+                .map(|(e, &width)| format!("{:>1$}", e, width))
+                .collect()
+        })
+        .collect()
+}
+
+fn text_matrix<T: Num + Copy + Display>(m: &Matrix<T>) -> String {
+    aligned(m)
+        .into_iter()
+        .map(|mut row| {
+            let b = row.pop().unwrap_or_default();
+            format!("[ {} | {} ]\n", row.join("  "), b)
+        })
+        .collect()
+}
+
+fn latex_matrix<T: Num + Copy + Display>(m: &Matrix<T>) -> String {
+    let body = m
+        .rows()
+        .map(|row| {
+            row.iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(" & ")
+        })
+        .collect::<Vec<_>>()
+        .join(" \\\\ ");
+    format!(
+        "\\left[\\begin{{array}}{{{}|c}} {} \\end{{array}}\\right]",
+        "c".repeat(m.cols - 1),
+        body
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mat,
+        solver::gauss::{self, PivotStrategy},
+    };
+
+    #[test]
+    fn test_trace_replays_elimination() {
+        let a = mat![[1.0, 1.0, 3.0], [2.0, 4.0, 10.0]];
+        let (solution, trace) = gauss::solve_with_trace(a.clone(), PivotStrategy::Partial);
+
+        assert_eq!(a, trace.initial);
+        assert_eq!(
+            vec![
+                Step::SwapRows { a: 0, b: 1 },
+                Step::Scale {
+                    row: 0,
+                    divisor: 2.0
+                },
+                Step::Eliminate {
+                    row: 1,
+                    pivot_row: 0,
+                    multiplier: 1.0
+                },
+                Step::Scale {
+                    row: 1,
+                    divisor: -1.0
+                },
+                Step::BackSubstitute {
+                    row: 0,
+                    pivot_row: 1,
+                    multiplier: 2.0
+                },
+            ],
+            trace.steps.iter().map(|s| s.step).collect::<Vec<_>>()
+        );
+        assert_eq!(
+            mat![[1.0, 0.0, 1.0], [0.0, 1.0, 2.0]],
+            trace.steps[4].matrix
+        );
+        assert_eq!(
+            vec![1.0, 2.0],
+            solution
+                .unwrap()
+                .solution
+                .iter()
+                .copied()
+                .collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_trace_kept_on_failure() {
+        let (solution, trace) = gauss::solve_with_trace(
+            mat![[1.0, 2.0, 3.0], [2.0, 4.0, 6.0]],
+            PivotStrategy::Partial,
+        );
+        assert!(solution.is_err());
+        assert_eq!(
+            mat![[1.0, 2.0, 3.0], [0.0, 0.0, 0.0]],
+            trace.steps.last().unwrap().matrix
+        );
+    }
+
+    #[test]
+    fn test_latex() {
+        let (_, trace) = gauss::solve_with_trace(mat![[2, 4]], PivotStrategy::None);
+        assert_eq!(
+            "\\begin{align*}\n& \\left[\\begin{array}{c|c} 2 & 4 \\end{array}\\right] \\\\\n\
+             \\xrightarrow{R_{1} / 2} \\quad & \\left[\\begin{array}{c|c} 1 & 2 \\end{array}\\right]\n\
+             \\end{align*}\n",
+            trace.to_latex()
+        );
+    }
+}
@@ -0,0 +1,311 @@
+//! Forward and back substitution, solving triangular systems Tx = b in O(n²).
+//!
+//! Each routine reads only the triangle it solves with, and the diagonal
+//! unless told it is all ones, so the strictly lower and upper halves of a
+//! packed factorization such as LU's can be used in place, without being
+//! copied out first.
+//!
+//! For a triangular `Matrix` kept as its own type, see
+//! [`structured`](../../structured/index.html).
+use crate::{
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    vector::Vector,
+};
+use num_traits::Num;
+
+/// Whether the diagonal of a triangular matrix is stored, or is implicitly
+/// all ones, as for the L of an LU decomposition.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum DiagonalKind {
+    /// The diagonal is all ones, and its stored entries are ignored.
+    Unit,
+    /// The diagonal is read from the matrix, and must have no zero.
+    NonUnit,
+}
+
+/// Solves Lx = b for x by forward substitution, where L is the lower triangle
+/// of `l`.
+///
+/// Returns an error if `l` is not square, `b` has the wrong length, or, for a
+/// `NonUnit` diagonal, L is singular, naming the first zero on its diagonal.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, row, vector::Row, solver::triangular::{self, DiagonalKind}};
+///
+/// let l = mat![[2.0, 0.0], [1.0, 4.0]];
+/// assert_eq!(Ok(row![1.0, 1.0]), triangular::forward_substitution(&l, &row![2.0, 5.0], DiagonalKind::NonUnit));
+/// assert_eq!(Ok(row![2.0, 3.0]), triangular::forward_substitution(&l, &row![2.0, 5.0], DiagonalKind::Unit));
+/// ```
+pub fn forward_substitution<T>(
+    l: &Matrix<T>,
+    b: &Vector<T>,
+    diagonal: DiagonalKind,
+) -> Result<Vector<T>>
+where
+    T: Num + Copy,
+{
+    let x = forward_substitution_matrix(l, &column(b), diagonal)?;
+    Ok(x.col(0))
+}
+
+/// Solves LX = B for X by forward substitution, treating each column of `b` as
+/// its own right-hand side.
+///
+/// Returns an error as [`forward_substitution`](fn.forward_substitution.html)
+/// does.
+pub fn forward_substitution_matrix<T>(
+    l: &Matrix<T>,
+    b: &Matrix<T>,
+    diagonal: DiagonalKind,
+) -> Result<Matrix<T>>
+where
+    T: Num + Copy,
+{
+    check(l, b, diagonal)?;
+
+    let mut x = b.clone();
+    for i in 0..l.rows {
+        for c in 0..x.cols {
+            let sum = (0..i).fold(x[i][c], |acc, j| acc - l[i][j] * x[j][c]);
+            x[i][c] = divide(sum, l[i][i], diagonal);
+        }
+    }
+    Ok(x)
+}
+
+/// Solves Ux = b for x by back substitution, where U is the upper triangle of
+/// `u`.
+///
+/// Returns an error if `u` is not square, `b` has the wrong length, or, for a
+/// `NonUnit` diagonal, U is singular, naming the first zero on its diagonal.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, row, vector::Row, solver::triangular::{self, DiagonalKind}};
+///
+/// let u = mat![[2.0, 1.0], [0.0, 4.0]];
+/// assert_eq!(Ok(row![1.0, 1.0]), triangular::back_substitution(&u, &row![3.0, 4.0], DiagonalKind::NonUnit));
+/// ```
+pub fn back_substitution<T>(
+    u: &Matrix<T>,
+    b: &Vector<T>,
+    diagonal: DiagonalKind,
+) -> Result<Vector<T>>
+where
+    T: Num + Copy,
+{
+    let x = back_substitution_matrix(u, &column(b), diagonal)?;
+    Ok(x.col(0))
+}
+
+/// Solves UX = B for X by back substitution, treating each column of `b` as its
+/// own right-hand side.
+///
+/// Returns an error as [`back_substitution`](fn.back_substitution.html) does.
+pub fn back_substitution_matrix<T>(
+    u: &Matrix<T>,
+    b: &Matrix<T>,
+    diagonal: DiagonalKind,
+) -> Result<Matrix<T>>
+where
+    T: Num + Copy,
+{
+    check(u, b, diagonal)?;
+
+    let n = u.rows;
+    let mut x = b.clone();
+    for i in (0..n).rev() {
+        for c in 0..x.cols {
+            let sum = (i + 1..n).fold(x[i][c], |acc, j| acc - u[i][j] * x[j][c]);
+            x[i][c] = divide(sum, u[i][i], diagonal);
+        }
+    }
+    Ok(x)
+}
+
+/// Solves Uᵀx = b for x by forward substitution, where U is the upper triangle
+/// of `u`, without forming Uᵀ.
+///
+/// Returns an error as [`back_substitution`](fn.back_substitution.html) does.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, row, vector::Row, solver::triangular::{self, DiagonalKind}};
+///
+/// let u = mat![[2.0, 1.0], [0.0, 4.0]];
+/// assert_eq!(Ok(row![1.0, 1.0]), triangular::forward_substitution_transpose(&u, &row![2.0, 5.0], DiagonalKind::NonUnit));
+/// ```
+pub fn forward_substitution_transpose<T>(
+    u: &Matrix<T>,
+    b: &Vector<T>,
+    diagonal: DiagonalKind,
+) -> Result<Vector<T>>
+where
+    T: Num + Copy,
+{
+    check(u, &column(b), diagonal)?;
+
+    // Row i of Uᵀ is column i of U.
+    let mut x = b.clone();
+    for i in 0..u.rows {
+        let sum = (0..i).fold(x[i], |acc, j| acc - u[j][i] * x[j]);
+        x[i] = divide(sum, u[i][i], diagonal);
+    }
+    Ok(x)
+}
+
+/// Solves Lᵀx = b for x by back substitution, where L is the lower triangle of
+/// `l`, without forming Lᵀ.
+///
+/// Returns an error as [`forward_substitution`](fn.forward_substitution.html)
+/// does.
+///
+/// # Example
+/// ```
+/// use launearalg::{mat, matrix::Matrix, row, vector::Row, solver::triangular::{self, DiagonalKind}};
+///
+/// let l = mat![[2.0, 0.0], [1.0, 4.0]];
+/// assert_eq!(Ok(row![1.0, 1.0]), triangular::back_substitution_transpose(&l, &row![3.0, 4.0], DiagonalKind::NonUnit));
+/// ```
+pub fn back_substitution_transpose<T>(
+    l: &Matrix<T>,
+    b: &Vector<T>,
+    diagonal: DiagonalKind,
+) -> Result<Vector<T>>
+where
+    T: Num + Copy,
+{
+    check(l, &column(b), diagonal)?;
+
+    // Row i of Lᵀ is column i of L.
+    let n = l.rows;
+    let mut x = b.clone();
+    for i in (0..n).rev() {
+        let sum = (i + 1..n).fold(x[i], |acc, j| acc - l[j][i] * x[j]);
+        x[i] = divide(sum, l[i][i], diagonal);
+    }
+    Ok(x)
+}
+
+fn column<T: Num + Copy>(b: &Vector<T>) -> Matrix<T> {
+    Matrix::from_fn(b.len(), 1, |i, _| b[i])
+}
+
+fn divide<T: Num + Copy>(sum: T, pivot: T, diagonal: DiagonalKind) -> T {
+    match diagonal {
+        DiagonalKind::Unit => sum,
+        DiagonalKind::NonUnit => sum / pivot,
+    }
+}
+
+// Checks that `t` is square, `b` has a row per row of `t`, and a stored
+// diagonal has no zero to divide by.
+fn check<T>(t: &Matrix<T>, b: &Matrix<T>, diagonal: DiagonalKind) -> Result<()>
+where
+    T: Num + Copy,
+{
+    if t.rows != t.cols {
+        return Err(LinalgError::NotSquare {
+            rows: t.rows,
+            cols: t.cols,
+        });
+    }
+    if b.rows != t.rows {
+        return Err(LinalgError::DimensionMismatch {
+            expected: (t.rows, b.cols),
+            found: (b.rows, b.cols),
+        });
+    }
+    if diagonal == DiagonalKind::NonUnit {
+        if let Some(pivot) = (0..t.rows).find(|&i| t[i][i].is_zero()) {
+            return Err(LinalgError::Singular { pivot });
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{mat, traits::Transpose};
+
+    #[test]
+    fn test_multiple_right_hand_sides() {
+        let l = mat![[2.0, 0.0, 0.0], [1.0, 1.0, 0.0], [-1.0, 3.0, 4.0]];
+        let x = mat![[1.0, 2.0], [-1.0, 0.5], [0.25, 3.0]];
+        let b = &l * &x;
+
+        assert_eq!(
+            Ok(x.clone()),
+            forward_substitution_matrix(&l, &b, DiagonalKind::NonUnit)
+        );
+
+        let u = l.transpose();
+        let b = &u * &x;
+        assert_eq!(
+            Ok(x),
+            back_substitution_matrix(&u, &b, DiagonalKind::NonUnit)
+        );
+    }
+
+    #[test]
+    fn test_other_triangle_is_ignored() {
+        // The packed LU factors of [[2, 1], [4, 5]], L below the diagonal and U on and above.
+        let packed = mat![[2, 1], [2, 3]];
+        let y =
+            forward_substitution(&packed, &Vector::from(vec![3, 9]), DiagonalKind::Unit).unwrap();
+        assert_eq!(Vector::from(vec![3, 3]), y);
+        assert_eq!(
+            Ok(Vector::from(vec![1, 1])),
+            back_substitution(&packed, &y, DiagonalKind::NonUnit)
+        );
+    }
+
+    #[test]
+    fn test_transposed_solves_agree_with_transposing() {
+        // Both triangles hold other data, which the transposed solves must ignore.
+        let packed = mat![[2.0, -1.0, 3.0], [1.0, 5.0, 0.5], [-1.0, 3.0, 4.0]];
+        let b = Vector::from(vec![1.0, -2.0, 6.0]);
+
+        assert_eq!(
+            forward_substitution(&packed.transpose(), &b, DiagonalKind::NonUnit),
+            forward_substitution_transpose(&packed, &b, DiagonalKind::NonUnit)
+        );
+        assert_eq!(
+            back_substitution(&packed.transpose(), &b, DiagonalKind::Unit),
+            back_substitution_transpose(&packed, &b, DiagonalKind::Unit)
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        let singular = mat![[1.0, 0.0], [2.0, 0.0]];
+        let b = Vector::from(vec![1.0, 1.0]);
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 1 }),
+            forward_substitution(&singular, &b, DiagonalKind::NonUnit)
+        );
+        assert!(forward_substitution(&singular, &b, DiagonalKind::Unit).is_ok());
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 1),
+                found: (3, 1)
+            }),
+            back_substitution(
+                &mat![[1.0, 2.0], [0.0, 1.0]],
+                &Vector::from(vec![1.0; 3]),
+                DiagonalKind::Unit
+            )
+        );
+        assert_eq!(
+            Err(LinalgError::NotSquare { rows: 1, cols: 2 }),
+            back_substitution(
+                &mat![[1.0, 2.0]],
+                &Vector::from(vec![1.0]),
+                DiagonalKind::Unit
+            )
+        );
+    }
+}
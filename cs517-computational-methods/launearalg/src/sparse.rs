@@ -0,0 +1,660 @@
+use crate::{
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    operator::LinearOperator,
+    solver::iterative::RowAccess,
+    vector::Vector,
+};
+use num_traits::{Float, Num, NumAssignOps};
+
+/// A sparse matrix under construction, kept as a list of (row, col, value)
+/// triplets in the order they were pushed.
+///
+/// Pushing the same position more than once is allowed, and the values are
+/// summed on conversion, which is convenient when assembling a system from
+/// per-element contributions. Convert to a `CsrMatrix` or `CscMatrix` to
+/// compute with it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CooMatrix<T> {
+    pub rows: usize,
+    pub cols: usize,
+    entries: Vec<(usize, usize, T)>,
+}
+
+impl<T> CooMatrix<T>
+where
+    T: Num + Copy + NumAssignOps,
+{
+    /// Create an empty rows x cols `CooMatrix`.
+    ///
+    /// # Panics
+    /// Panics if rows or cols ≦ 0, as its dense `Matrix` could not exist.
+    pub fn new(rows: usize, cols: usize) -> Self {
+        assert!(rows > 0 && cols > 0);
+        CooMatrix {
+            rows,
+            cols,
+            entries: Vec::new(),
+        }
+    }
+
+    /// Fallible version of [`new`](#method.new), returning `LinalgError::Empty`
+    /// rather than panicking if rows or cols ≦ 0.
+    pub fn try_new(rows: usize, cols: usize) -> Result<Self> {
+        if rows == 0 || cols == 0 {
+            return Err(LinalgError::Empty);
+        }
+        Ok(CooMatrix::new(rows, cols))
+    }
+
+    /// Add `value` at (i, j).
+    ///
+    /// # Panics
+    /// Panics if (i, j) is outside the matrix.
+    pub fn push(&mut self, i: usize, j: usize, value: T) {
+        assert!(
+            i < self.rows && j < self.cols,
+            "({}, {}) is outside a {}x{} matrix",
+            i,
+            j,
+            self.rows,
+            self.cols
+        );
+        self.entries.push((i, j, value));
+    }
+
+    /// Number of triplets pushed, counting repeated positions separately.
+    pub fn nnz(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// The triplets pushed so far.
+    pub fn entries(&self) -> &[(usize, usize, T)] {
+        &self.entries
+    }
+
+    /// Compress into row-major storage, summing repeated positions.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix, sparse::CooMatrix};
+    ///
+    /// let mut coo = CooMatrix::new(2, 3);
+    /// coo.push(1, 2, 4);
+    /// coo.push(0, 0, 1);
+    /// coo.push(1, 2, 1);
+    ///
+    /// let csr = coo.to_csr();
+    /// assert_eq!(2, csr.nnz());
+    /// assert_eq!(mat![[1, 0, 0], [0, 0, 5]], csr.to_matrix());
+    /// ```
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let (row_offsets, col_indices, values) = compress(
+            self.rows,
+            self.entries.iter().map(|(i, j, v)| (*i, *j, *v)).collect(),
+        );
+        CsrMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    /// Compress into column-major storage, summing repeated positions.
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let (col_offsets, row_indices, values) = compress(
+            self.cols,
+            self.entries.iter().map(|(i, j, v)| (*j, *i, *v)).collect(),
+        );
+        CscMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+}
+
+/// A sparse matrix in compressed sparse row form.
+///
+/// The stored entries of row i are `values[row_offsets[i]..row_offsets[i + 1]]`,
+/// in columns `col_indices` over the same range, sorted ascending. Storage
+/// is O(rows + nnz), and a matrix-vector product costs O(nnz).
+#[derive(Debug, PartialEq, Clone)]
+pub struct CsrMatrix<T> {
+    pub rows: usize,
+    pub cols: usize,
+    row_offsets: Vec<usize>,
+    col_indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CsrMatrix<T>
+where
+    T: Num + Copy + NumAssignOps,
+{
+    /// Number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// The column indices and values stored in row `i`.
+    pub fn row(&self, i: usize) -> (&[usize], &[T]) {
+        let range = self.row_offsets[i]..self.row_offsets[i + 1];
+        (&self.col_indices[range.clone()], &self.values[range])
+    }
+
+    /// The entry at (i, j), zero if it is not stored.
+    ///
+    /// # Panics
+    /// Panics if (i, j) is outside the matrix.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        assert!(
+            i < self.rows && j < self.cols,
+            "({}, {}) is outside a {}x{} matrix",
+            i,
+            j,
+            self.rows,
+            self.cols
+        );
+        let (cols, values) = self.row(i);
+        match cols.binary_search(&j) {
+            Ok(k) => values[k],
+            Err(_) => T::zero(),
+        }
+    }
+
+    /// Computes Ax.
+    ///
+    /// # Panics
+    /// Panics if `x` does not have as many entries as the matrix has columns.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, row, matrix::Matrix, sparse::CsrMatrix, vector::Row};
+    ///
+    /// let a = CsrMatrix::from(&mat![[2, 0, 0], [0, 0, 3]]);
+    /// assert_eq!(row![2, 9], a.mul_vector(&row![1, 2, 3]));
+    /// ```
+    pub fn mul_vector(&self, x: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.cols, x.len());
+
+        Vector::from(
+            (0..self.rows)
+                .map(|i| {
+                    let (cols, values) = self.row(i);
+                    cols.iter()
+                        .zip(values.iter())
+                        .fold(T::zero(), |acc, (j, v)| acc + *v * x[*j])
+                })
+                .collect::<Vec<T>>(),
+        )
+    }
+
+    /// Computes AB for a dense `Matrix` B.
+    ///
+    /// # Panics
+    /// Panics if B does not have as many rows as A has columns.
+    pub fn mul_matrix(&self, b: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.cols, b.rows);
+
+        let mut product = Matrix::new(self.rows, b.cols);
+        for i in 0..self.rows {
+            let (cols, values) = self.row(i);
+            for (j, v) in cols.iter().zip(values.iter()) {
+                for (p, bjk) in product[i].iter_mut().zip(b[*j].iter()) {
+                    *p += *v * *bjk;
+                }
+            }
+        }
+        product
+    }
+
+    /// The transpose Aᵀ, also in row-major form.
+    pub fn transpose(&self) -> CsrMatrix<T> {
+        // The column-major arrays of A are the row-major arrays of Aᵀ.
+        let csc = self.to_csc();
+        CsrMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            row_offsets: csc.col_offsets,
+            col_indices: csc.row_indices,
+            values: csc.values,
+        }
+    }
+
+    /// The same matrix in column-major form.
+    pub fn to_csc(&self) -> CscMatrix<T> {
+        let (col_offsets, row_indices, values) = compress(
+            self.cols,
+            self.triplets().map(|(i, j, v)| (j, i, v)).collect(),
+        );
+        CscMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            col_offsets,
+            row_indices,
+            values,
+        }
+    }
+
+    /// The equivalent dense `Matrix`.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let mut m = Matrix::new(self.rows, self.cols);
+        for (i, j, v) in self.triplets() {
+            m[i][j] = v;
+        }
+        m
+    }
+
+    fn triplets(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        (0..self.rows).flat_map(move |i| {
+            let range = self.row_offsets[i]..self.row_offsets[i + 1];
+            range.map(move |k| (i, self.col_indices[k], self.values[k]))
+        })
+    }
+}
+
+impl<T> From<&Matrix<T>> for CsrMatrix<T>
+where
+    T: Num + Copy + NumAssignOps,
+{
+    /// Keep the non-zero entries of a dense `Matrix`.
+    fn from(m: &Matrix<T>) -> Self {
+        let mut row_offsets = Vec::with_capacity(m.rows + 1);
+        let mut col_indices = Vec::new();
+        let mut values = Vec::new();
+
+        row_offsets.push(0);
+        for row in m.iter() {
+            for (j, v) in row.iter().enumerate() {
+                if !v.is_zero() {
+                    col_indices.push(j);
+                    values.push(*v);
+                }
+            }
+            row_offsets.push(values.len());
+        }
+
+        CsrMatrix {
+            rows: m.rows,
+            cols: m.cols,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+}
+
+impl<T> RowAccess<T> for CsrMatrix<T>
+where
+    T: Float + NumAssignOps,
+{
+    fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn get(&self, i: usize, j: usize) -> T {
+        CsrMatrix::get(self, i, j)
+    }
+
+    fn for_each_in_row<F>(&self, i: usize, mut f: F)
+    where
+        F: FnMut(usize, T),
+    {
+        let (cols, values) = self.row(i);
+        for (j, v) in cols.iter().zip(values.iter()) {
+            f(*j, *v);
+        }
+    }
+}
+
+impl<T> LinearOperator<T> for CsrMatrix<T>
+where
+    T: Num + Copy + NumAssignOps,
+{
+    fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn apply(&self, x: &Vector<T>) -> Vector<T> {
+        self.mul_vector(x)
+    }
+
+    // Scatters each row of A, weighted by x, without forming Aᵀ.
+    fn apply_transpose(&self, x: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.rows, x.len());
+
+        let mut product = vec![T::zero(); self.cols];
+        for (i, xi) in x.iter().enumerate() {
+            let (cols, values) = self.row(i);
+            for (j, v) in cols.iter().zip(values.iter()) {
+                product[*j] += *v * *xi;
+            }
+        }
+        Vector::from(product)
+    }
+}
+
+/// A sparse matrix in compressed sparse column form.
+///
+/// The stored entries of column j are `values[col_offsets[j]..col_offsets[j + 1]]`,
+/// in rows `row_indices` over the same range, sorted ascending.
+#[derive(Debug, PartialEq, Clone)]
+pub struct CscMatrix<T> {
+    pub rows: usize,
+    pub cols: usize,
+    col_offsets: Vec<usize>,
+    row_indices: Vec<usize>,
+    values: Vec<T>,
+}
+
+impl<T> CscMatrix<T>
+where
+    T: Num + Copy + NumAssignOps,
+{
+    /// Number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// The row indices and values stored in column `j`.
+    pub fn col(&self, j: usize) -> (&[usize], &[T]) {
+        let range = self.col_offsets[j]..self.col_offsets[j + 1];
+        (&self.row_indices[range.clone()], &self.values[range])
+    }
+
+    /// The entry at (i, j), zero if it is not stored.
+    ///
+    /// # Panics
+    /// Panics if (i, j) is outside the matrix.
+    pub fn get(&self, i: usize, j: usize) -> T {
+        assert!(
+            i < self.rows && j < self.cols,
+            "({}, {}) is outside a {}x{} matrix",
+            i,
+            j,
+            self.rows,
+            self.cols
+        );
+        let (rows, values) = self.col(j);
+        match rows.binary_search(&i) {
+            Ok(k) => values[k],
+            Err(_) => T::zero(),
+        }
+    }
+
+    /// Computes Ax.
+    ///
+    /// # Panics
+    /// Panics if `x` does not have as many entries as the matrix has columns.
+    pub fn mul_vector(&self, x: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.cols, x.len());
+
+        let mut product = vec![T::zero(); self.rows];
+        for j in 0..self.cols {
+            let (rows, values) = self.col(j);
+            for (i, v) in rows.iter().zip(values.iter()) {
+                product[*i] += *v * x[j];
+            }
+        }
+        Vector::from(product)
+    }
+
+    /// Computes AB for a dense `Matrix` B.
+    ///
+    /// # Panics
+    /// Panics if B does not have as many rows as A has columns.
+    pub fn mul_matrix(&self, b: &Matrix<T>) -> Matrix<T> {
+        assert_eq!(self.cols, b.rows);
+
+        let mut product = Matrix::new(self.rows, b.cols);
+        for j in 0..self.cols {
+            let (rows, values) = self.col(j);
+            for (i, v) in rows.iter().zip(values.iter()) {
+                for (p, bjk) in product[*i].iter_mut().zip(b[j].iter()) {
+                    *p += *v * *bjk;
+                }
+            }
+        }
+        product
+    }
+
+    /// The transpose Aᵀ, also in column-major form.
+    pub fn transpose(&self) -> CscMatrix<T> {
+        let csr = self.to_csr();
+        CscMatrix {
+            rows: self.cols,
+            cols: self.rows,
+            col_offsets: csr.row_offsets,
+            row_indices: csr.col_indices,
+            values: csr.values,
+        }
+    }
+
+    /// The same matrix in row-major form.
+    pub fn to_csr(&self) -> CsrMatrix<T> {
+        let (row_offsets, col_indices, values) = compress(self.rows, self.triplets().collect());
+        CsrMatrix {
+            rows: self.rows,
+            cols: self.cols,
+            row_offsets,
+            col_indices,
+            values,
+        }
+    }
+
+    /// The equivalent dense `Matrix`.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let mut m = Matrix::new(self.rows, self.cols);
+        for (i, j, v) in self.triplets() {
+            m[i][j] = v;
+        }
+        m
+    }
+
+    fn triplets(&self) -> impl Iterator<Item = (usize, usize, T)> + '_ {
+        (0..self.cols).flat_map(move |j| {
+            let range = self.col_offsets[j]..self.col_offsets[j + 1];
+            range.map(move |k| (self.row_indices[k], j, self.values[k]))
+        })
+    }
+}
+
+impl<T> From<&Matrix<T>> for CscMatrix<T>
+where
+    T: Num + Copy + NumAssignOps,
+{
+    /// Keep the non-zero entries of a dense `Matrix`.
+    fn from(m: &Matrix<T>) -> Self {
+        CsrMatrix::from(m).to_csc()
+    }
+}
+
+impl<T> LinearOperator<T> for CscMatrix<T>
+where
+    T: Num + Copy + NumAssignOps,
+{
+    fn dims(&self) -> (usize, usize) {
+        (self.rows, self.cols)
+    }
+
+    fn apply(&self, x: &Vector<T>) -> Vector<T> {
+        self.mul_vector(x)
+    }
+
+    // Each entry of Aᵀx is a column of A dotted with x.
+    fn apply_transpose(&self, x: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.rows, x.len());
+
+        Vector::from(
+            (0..self.cols)
+                .map(|j| {
+                    let (rows, values) = self.col(j);
+                    rows.iter()
+                        .zip(values.iter())
+                        .fold(T::zero(), |acc, (i, v)| acc + *v * x[*i])
+                })
+                .collect::<Vec<T>>(),
+        )
+    }
+}
+
+// Sorts (major, minor, value) triplets and packs them into offsets, indices
+// and values, summing repeated positions.
+fn compress<T>(
+    majors: usize,
+    mut triplets: Vec<(usize, usize, T)>,
+) -> (Vec<usize>, Vec<usize>, Vec<T>)
+where
+    T: Num + Copy + NumAssignOps,
+{
+    triplets.sort_by_key(|(major, minor, _)| (*major, *minor));
+
+    let mut offsets = vec![0; majors + 1];
+    let mut indices: Vec<usize> = Vec::with_capacity(triplets.len());
+    let mut values: Vec<T> = Vec::with_capacity(triplets.len());
+    let mut last = None;
+
+    for (major, minor, value) in triplets {
+        if last == Some((major, minor)) {
+            *values.last_mut().unwrap() += value;
+            continue;
+        }
+        last = Some((major, minor));
+        offsets[major + 1] += 1;
+        indices.push(minor);
+        values.push(value);
+    }
+    for k in 0..majors {
+        offsets[k + 1] += offsets[k];
+    }
+
+    (offsets, indices, values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        mat, row,
+        solver::iterative::{conjugate_gradient, gauss_seidel, IterativeSettings},
+        traits::Transpose,
+        vector::Row,
+    };
+
+    // The 1D finite-difference Laplacian with Dirichlet ends, scaled to be
+    // positive definite.
+    fn laplacian(n: usize) -> CooMatrix<f64> {
+        let mut coo = CooMatrix::new(n, n);
+        for i in 0..n {
+            coo.push(i, i, 2.0);
+            if i > 0 {
+                coo.push(i, i - 1, -1.0);
+                coo.push(i - 1, i, -1.0);
+            }
+        }
+        coo
+    }
+
+    #[test]
+    fn test_conversions_round_trip() {
+        let dense = mat![[0, 1, 0, 2], [0, 0, 0, 0], [3, 0, 4, 0]];
+        let csr = CsrMatrix::from(&dense);
+        let csc = CscMatrix::from(&dense);
+
+        assert_eq!(4, csr.nnz());
+        assert_eq!(dense, csr.to_matrix());
+        assert_eq!(dense, csc.to_matrix());
+        assert_eq!(csr, csc.to_csr());
+        assert_eq!(csc, csr.to_csc());
+        assert_eq!((&[0usize, 2][..], &[3, 4][..]), csr.row(2));
+        assert_eq!((&[0usize][..], &[2][..]), csc.col(3));
+        assert_eq!(4, csc.get(2, 2));
+        assert_eq!(0, csr.get(1, 3));
+    }
+
+    #[test]
+    fn test_coo_sums_duplicates() {
+        let mut coo = CooMatrix::new(2, 2);
+        coo.push(0, 1, 1.5);
+        coo.push(1, 0, 2.0);
+        coo.push(0, 1, 1.5);
+
+        assert_eq!(3, coo.nnz());
+        assert_eq!(mat![[0.0, 3.0], [2.0, 0.0]], coo.to_csr().to_matrix());
+        assert_eq!(mat![[0.0, 3.0], [2.0, 0.0]], coo.to_csc().to_matrix());
+    }
+
+    #[test]
+    fn test_coo_rejects_empty_shape() {
+        assert_eq!(Err(LinalgError::Empty), CooMatrix::<f64>::try_new(0, 3));
+        assert_eq!(Err(LinalgError::Empty), CooMatrix::<f64>::try_new(3, 0));
+        assert_eq!(Ok(CooMatrix::new(1, 1)), CooMatrix::<f64>::try_new(1, 1));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_coo_new_panics_on_empty_shape() {
+        CooMatrix::<f64>::new(0, 0);
+    }
+
+    #[test]
+    fn test_products_match_dense() {
+        let dense = mat![[1, 0, 2], [0, 3, 0], [4, 0, 5], [0, 0, 6]];
+        let x = row![1, 2, 3];
+        let b = mat![[1, 2], [3, 4], [5, 6]];
+        let expected = &dense * &b;
+
+        let csr = CsrMatrix::from(&dense);
+        let csc = CscMatrix::from(&dense);
+
+        assert_eq!(row![7, 6, 19, 18], csr.mul_vector(&x));
+        assert_eq!(row![7, 6, 19, 18], csc.mul_vector(&x));
+        assert_eq!(expected, csr.mul_matrix(&b));
+        assert_eq!(expected, csc.mul_matrix(&b));
+    }
+
+    #[test]
+    fn test_transpose() {
+        let dense = mat![[1, 0, 2], [0, 3, 0]];
+        assert_eq!(
+            dense.transpose(),
+            CsrMatrix::from(&dense).transpose().to_matrix()
+        );
+        assert_eq!(
+            dense.transpose(),
+            CscMatrix::from(&dense).transpose().to_matrix()
+        );
+    }
+
+    #[test]
+    fn test_iterative_solvers_on_csr() {
+        let n = 200;
+        let a = laplacian(n).to_csr();
+        assert_eq!(3 * n - 2, a.nnz());
+
+        let b = Vector::from(vec![1.0; n]);
+        let settings = IterativeSettings {
+            tolerance: 1e-10,
+            max_iterations: 1000,
+        };
+        let cg = conjugate_gradient(&a, &b, &settings).unwrap();
+        assert!(cg.converged);
+        assert!(cg.iterations <= n);
+
+        // -u'' = 1 with u(0) = u(n + 1) = 0 has u(i) = i(n + 1 - i) / 2.
+        for i in 0..n {
+            let exact = ((i + 1) * (n - i)) as f64 / 2.0;
+            assert!((cg.solution[i] - exact).abs() < 1e-6 * exact);
+        }
+
+        let small = laplacian(10).to_csr();
+        let gs = gauss_seidel(&small, &Vector::from(vec![1.0; 10]), &settings).unwrap();
+        assert!(gs.converged);
+        assert!((gs.solution[0] - 5.0).abs() < 1e-8);
+    }
+}
@@ -0,0 +1,408 @@
+//! Square matrices of known structure, kept as their own types so that solving
+//! with them runs the routine specialised to that structure.
+//!
+//! `LowerTriangular`, `UpperTriangular` and `Symmetric` wrap the `Matrix` they
+//! were built from, checking its structure once up front; `Diagonal` keeps only
+//! its diagonal.
+//!
+//! ```
+//! use launearalg::{mat, matrix::Matrix, row, vector::Row, structured::UpperTriangular, traits::Transpose};
+//!
+//! let u = UpperTriangular::new(mat![[2.0, 1.0], [0.0, 4.0]]).unwrap();
+//! assert_eq!(Ok(row![1.0, 1.0]), u.solve(&row![3.0, 4.0]));
+//! assert_eq!(Ok(row![1.5, 0.625]), u.transpose().solve(&row![3.0, 4.0]));
+//! ```
+use crate::{
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    solver::{
+        cholesky::{self, Cholesky},
+        lu::LuFactorization,
+        triangular::{self, DiagonalKind},
+    },
+    traits::Transpose,
+    vector::Vector,
+};
+use num_traits::{Float, Num, NumAssignOps, Signed};
+
+/// A square `Matrix` with only zeros above its diagonal.
+#[derive(Debug, PartialEq, Clone)]
+pub struct LowerTriangular<T> {
+    matrix: Matrix<T>,
+}
+
+/// A square `Matrix` with only zeros below its diagonal.
+#[derive(Debug, PartialEq, Clone)]
+pub struct UpperTriangular<T> {
+    matrix: Matrix<T>,
+}
+
+impl<T> LowerTriangular<T>
+where
+    T: Num + Copy,
+{
+    /// Wrap the lower triangular `Matrix` `m`.
+    ///
+    /// Returns an error if `m` is not square, or naming the first non-zero
+    /// entry above its diagonal.
+    pub fn new(m: Matrix<T>) -> Result<Self> {
+        check_triangle(&m, |i, j| j > i)?;
+        Ok(LowerTriangular { matrix: m })
+    }
+
+    /// The wrapped `Matrix`.
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.matrix
+    }
+
+    /// Unwrap the `Matrix`.
+    pub fn into_inner(self) -> Matrix<T> {
+        self.matrix
+    }
+
+    /// Solve Lx = b for x by forward substitution.
+    ///
+    /// Returns an error if `b` has the wrong length or L is singular.
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>> {
+        triangular::forward_substitution(&self.matrix, b, DiagonalKind::NonUnit)
+    }
+
+    /// Solve LX = B for X, treating each column of `b` as its own right-hand
+    /// side.
+    ///
+    /// Returns an error if `b` has the wrong number of rows or L is singular.
+    pub fn solve_matrix(&self, b: &Matrix<T>) -> Result<Matrix<T>> {
+        triangular::forward_substitution_matrix(&self.matrix, b, DiagonalKind::NonUnit)
+    }
+
+    /// The determinant, the product of the diagonal.
+    pub fn determinant(&self) -> T {
+        diagonal_product(&self.matrix)
+    }
+}
+
+impl<T> UpperTriangular<T>
+where
+    T: Num + Copy,
+{
+    /// Wrap the upper triangular `Matrix` `m`.
+    ///
+    /// Returns an error if `m` is not square, or naming the first non-zero
+    /// entry below its diagonal.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{error::LinalgError, mat, matrix::Matrix, structured::UpperTriangular};
+    ///
+    /// assert!(UpperTriangular::new(mat![[1, 2], [0, 3]]).is_ok());
+    /// assert_eq!(
+    ///     Err(LinalgError::NotTriangular { row: 1, col: 0 }),
+    ///     UpperTriangular::new(mat![[1, 2], [4, 3]])
+    /// );
+    /// ```
+    pub fn new(m: Matrix<T>) -> Result<Self> {
+        check_triangle(&m, |i, j| j < i)?;
+        Ok(UpperTriangular { matrix: m })
+    }
+
+    /// The wrapped `Matrix`.
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.matrix
+    }
+
+    /// Unwrap the `Matrix`.
+    pub fn into_inner(self) -> Matrix<T> {
+        self.matrix
+    }
+
+    /// Solve Ux = b for x by back substitution.
+    ///
+    /// Returns an error if `b` has the wrong length or U is singular.
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>> {
+        triangular::back_substitution(&self.matrix, b, DiagonalKind::NonUnit)
+    }
+
+    /// Solve UX = B for X, treating each column of `b` as its own right-hand
+    /// side.
+    ///
+    /// Returns an error if `b` has the wrong number of rows or U is singular.
+    pub fn solve_matrix(&self, b: &Matrix<T>) -> Result<Matrix<T>> {
+        triangular::back_substitution_matrix(&self.matrix, b, DiagonalKind::NonUnit)
+    }
+
+    /// The determinant, the product of the diagonal.
+    pub fn determinant(&self) -> T {
+        diagonal_product(&self.matrix)
+    }
+}
+
+impl<T> Transpose for LowerTriangular<T>
+where
+    T: Copy,
+{
+    type Output = UpperTriangular<T>;
+    fn transpose(&self) -> Self::Output {
+        UpperTriangular {
+            matrix: self.matrix.transpose(),
+        }
+    }
+}
+
+impl<T> Transpose for UpperTriangular<T>
+where
+    T: Copy,
+{
+    type Output = LowerTriangular<T>;
+    fn transpose(&self) -> Self::Output {
+        LowerTriangular {
+            matrix: self.matrix.transpose(),
+        }
+    }
+}
+
+/// A square `Matrix` equal to its own transpose.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Symmetric<T> {
+    matrix: Matrix<T>,
+}
+
+impl<T> Symmetric<T>
+where
+    T: Float + Signed + NumAssignOps,
+{
+    /// Wrap the symmetric `Matrix` `m`.
+    ///
+    /// Returns an error if `m` is not square, has a NaN or infinite entry, or
+    /// differs from its transpose by more than rounding error.
+    pub fn new(m: Matrix<T>) -> Result<Self> {
+        cholesky::check_symmetric(&m)?;
+        Ok(Symmetric { matrix: m })
+    }
+
+    /// The wrapped `Matrix`.
+    pub fn matrix(&self) -> &Matrix<T> {
+        &self.matrix
+    }
+
+    /// Unwrap the `Matrix`.
+    pub fn into_inner(self) -> Matrix<T> {
+        self.matrix
+    }
+
+    /// Solve Ax = b for x by Cholesky decomposition, for half the work of LU,
+    /// falling back to LU decomposition if A turns out not to be positive
+    /// definite.
+    ///
+    /// Returns an error if `b` has the wrong length or A is singular.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix, row, vector::Row, structured::Symmetric};
+    ///
+    /// let positive_definite = Symmetric::new(mat![[4.0, 2.0], [2.0, 5.0]]).unwrap();
+    /// assert_eq!(Ok(row![1.0, 1.0]), positive_definite.solve(&row![6.0, 7.0]));
+    ///
+    /// let indefinite = Symmetric::new(mat![[0.0, 1.0], [1.0, 0.0]]).unwrap();
+    /// assert_eq!(Ok(row![2.0, 1.0]), indefinite.solve(&row![1.0, 2.0]));
+    /// ```
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>> {
+        let n = self.matrix.rows;
+        if b.len() != n {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (n, 1),
+                found: (b.len(), 1),
+            });
+        }
+        match Cholesky::new(&self.matrix) {
+            Ok(chol) => Ok(chol.solve(b)),
+            Err(LinalgError::NotPositiveDefinite { .. }) => {
+                Ok(LuFactorization::new(&self.matrix)?.solve(b))
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl<T> Transpose for Symmetric<T>
+where
+    T: Copy,
+{
+    type Output = Symmetric<T>;
+    fn transpose(&self) -> Self::Output {
+        self.clone()
+    }
+}
+
+/// A square matrix whose only non-zero entries lie on its diagonal, of which
+/// only the diagonal is stored.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Diagonal<T> {
+    diagonal: Vector<T>,
+}
+
+impl<T> Diagonal<T>
+where
+    T: Num + Copy,
+{
+    /// Create the n x n `Diagonal` matrix with the n entries of `diagonal`.
+    pub fn new(diagonal: Vector<T>) -> Self {
+        Diagonal { diagonal }
+    }
+
+    /// Number of rows, which is also the number of columns.
+    pub fn size(&self) -> usize {
+        self.diagonal.len()
+    }
+
+    /// The diagonal, entries (i, i).
+    pub fn diagonal(&self) -> &Vector<T> {
+        &self.diagonal
+    }
+
+    /// Computes Dx, scaling each entry of `x`.
+    ///
+    /// # Panics
+    /// Panics if `x` does not have as many entries as the matrix has rows.
+    pub fn mul_vector(&self, x: &Vector<T>) -> Vector<T> {
+        assert_eq!(self.size(), x.len());
+        self.diagonal
+            .iter()
+            .zip(x.iter())
+            .map(|(&d, &xi)| d * xi)
+            .collect()
+    }
+
+    /// Solve Dx = b for x, dividing each entry of `b` by its diagonal entry.
+    ///
+    /// Returns an error if `b` has the wrong length, or D is singular, naming
+    /// the first zero on its diagonal.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{row, vector::Row, structured::Diagonal};
+    ///
+    /// let d = Diagonal::new(row![2.0, 4.0]);
+    /// assert_eq!(Ok(row![1.5, 0.25]), d.solve(&row![3.0, 1.0]));
+    /// ```
+    pub fn solve(&self, b: &Vector<T>) -> Result<Vector<T>> {
+        if b.len() != self.size() {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (self.size(), 1),
+                found: (b.len(), 1),
+            });
+        }
+        if let Some(pivot) = self.diagonal.iter().position(|d| d.is_zero()) {
+            return Err(LinalgError::Singular { pivot });
+        }
+        Ok(self
+            .diagonal
+            .iter()
+            .zip(b.iter())
+            .map(|(&d, &bi)| bi / d)
+            .collect())
+    }
+
+    /// The determinant, the product of the diagonal.
+    pub fn determinant(&self) -> T {
+        self.diagonal.iter().fold(T::one(), |acc, &d| acc * d)
+    }
+
+    /// The equivalent dense `Matrix`.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        let n = self.size();
+        Matrix::from_fn(
+            n,
+            n,
+            |i, j| if i == j { self.diagonal[i] } else { T::zero() },
+        )
+    }
+}
+
+impl<T> Transpose for Diagonal<T>
+where
+    T: Copy,
+{
+    type Output = Diagonal<T>;
+    fn transpose(&self) -> Self::Output {
+        self.clone()
+    }
+}
+
+// Checks that `m` is square with zeros wherever `outside(i, j)`, reporting the
+// first non-zero entry in row-major order.
+fn check_triangle<T, F>(m: &Matrix<T>, outside: F) -> Result<()>
+where
+    T: Num + Copy,
+    F: Fn(usize, usize) -> bool,
+{
+    if m.rows != m.cols {
+        return Err(LinalgError::NotSquare {
+            rows: m.rows,
+            cols: m.cols,
+        });
+    }
+    for i in 0..m.rows {
+        if let Some(j) = (0..m.cols).find(|&j| outside(i, j) && !m[i][j].is_zero()) {
+            return Err(LinalgError::NotTriangular { row: i, col: j });
+        }
+    }
+    Ok(())
+}
+
+fn diagonal_product<T: Num + Copy>(m: &Matrix<T>) -> T {
+    (0..m.rows).fold(T::one(), |acc, i| acc * m[i][i])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mat;
+
+    #[test]
+    fn test_triangular_solves_agree_with_lu() {
+        let l =
+            LowerTriangular::new(mat![[3.0, 0.0, 0.0], [1.0, -2.0, 0.0], [4.0, 0.5, 1.0]]).unwrap();
+        let b = Vector::from(vec![1.0, 2.0, -3.0]);
+        let dense = LuFactorization::new(l.matrix()).unwrap();
+        let close = |x: Vector<f64>, y: Vector<f64>| (&x - &y).norm_inf() < 1e-15;
+
+        assert!(close(dense.solve(&b), l.solve(&b).unwrap()));
+        assert!(close(
+            dense.solve_transpose(&b),
+            l.transpose().solve(&b).unwrap()
+        ));
+        assert_eq!(-6.0, l.determinant());
+        assert_eq!(
+            Err(LinalgError::NotTriangular { row: 0, col: 2 }),
+            LowerTriangular::new(mat![[1, 0, 5], [1, 1, 0], [1, 1, 1]])
+        );
+    }
+
+    #[test]
+    fn test_symmetric_rejects_asymmetric() {
+        assert_eq!(
+            Err(LinalgError::NotSymmetric),
+            Symmetric::new(mat![[1.0, 2.0], [3.0, 1.0]])
+        );
+        let s = Symmetric::new(mat![[1.0, 2.0], [2.0, 4.0]]).unwrap();
+        assert!(matches!(
+            s.solve(&Vector::from(vec![1.0, 1.0])),
+            Err(LinalgError::Singular { .. })
+        ));
+    }
+
+    #[test]
+    fn test_diagonal() {
+        let d = Diagonal::new(Vector::from(vec![2, 0, 3]));
+        assert_eq!(mat![[2, 0, 0], [0, 0, 0], [0, 0, 3]], d.to_matrix());
+        assert_eq!(
+            Vector::from(vec![2, 0, 9]),
+            d.mul_vector(&Vector::from(vec![1, 5, 3]))
+        );
+        assert_eq!(0, d.determinant());
+        assert_eq!(
+            Err(LinalgError::Singular { pivot: 1 }),
+            d.solve(&Vector::from(vec![1, 1, 1]))
+        );
+    }
+}
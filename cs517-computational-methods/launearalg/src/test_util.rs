@@ -0,0 +1,52 @@
+//! Fixtures and assertions shared by the unit tests of several modules.
+use crate::{banded::TridiagonalMatrix, matrix::Matrix, vector::Vector};
+
+const TOLERANCE: f64 = 1e-10;
+
+/// Asserts `actual` is within 1e-10 of `expected`.
+pub fn assert_close(expected: f64, actual: f64) {
+    assert!(
+        (expected - actual).abs() < TOLERANCE,
+        "{} != {}",
+        expected,
+        actual
+    );
+}
+
+/// Asserts `actual` has the length of `expected`, and each entry within 1e-10.
+pub fn assert_vector_close(expected: &Vector<f64>, actual: &Vector<f64>) {
+    assert_eq!(expected.len(), actual.len());
+    for (&e, &a) in expected.iter().zip(actual.iter()) {
+        assert_close(e, a);
+    }
+}
+
+/// Asserts `actual` has the shape of `expected`, and each entry within 1e-10.
+pub fn assert_matrix_close(expected: &Matrix<f64>, actual: &Matrix<f64>) {
+    assert_eq!((expected.rows, expected.cols), (actual.rows, actual.cols));
+    for (e, a) in expected.rows().zip(actual.rows()) {
+        for (&e, &a) in e.iter().zip(a.iter()) {
+            assert_close(e, a);
+        }
+    }
+}
+
+/// The m x n Hilbert matrix, hᵢⱼ = 1 / (i + j + 1), notoriously ill-conditioned.
+pub fn hilbert(m: usize, n: usize) -> Matrix<f64> {
+    Matrix::from_fn(m, n, |i, j| 1.0 / (i + j + 1) as f64)
+}
+
+/// The n x n system `CubicSplineInterpolator` builds for unevenly spaced knots,
+/// as it sees for irregular samples. Diagonally dominant, but not symmetric.
+pub fn spline_system(n: usize) -> TridiagonalMatrix<f64> {
+    let h: Vec<f64> = (0..n).map(|i| 1.0 + (i % 3) as f64).collect();
+    let mut t = TridiagonalMatrix::new(n);
+    t.diagonal_mut()[0] = 1.0;
+    t.diagonal_mut()[n - 1] = 1.0;
+    for i in 1..n - 1 {
+        t.lower_mut()[i - 1] = h[i - 1];
+        t.diagonal_mut()[i] = 2.0 * (h[i - 1] + h[i]);
+        t.upper_mut()[i] = h[i];
+    }
+    t
+}
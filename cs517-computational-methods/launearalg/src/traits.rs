@@ -1,3 +1,5 @@
+use crate::error::LinalgError;
+
 /// Appends b onto self
 pub trait Augment<B = Self> {
     type Output;
@@ -27,7 +29,9 @@ where
 {
     type Output: ?Sized;
     fn analyze_piecewise(&mut self, points: Vec<(f64, f64)>) -> Option<Box<Self::Output>>;
-    fn analyze_global(&mut self) -> Option<Box<Self::Output>>;
+    /// Analyzes every point seen by `analyze_piecewise`, returning `Ok(None)` for
+    /// analyzers which have no global solution.
+    fn analyze_global(&mut self) -> Result<Option<Box<Self::Output>>, LinalgError>;
 }
 
 pub trait Approximate
@@ -1,7 +1,14 @@
 #![allow(clippy::len_without_is_empty)]
-use crate::traits::Augment;
-use num_traits::Num;
-use std::ops::{Add, Div, DivAssign, Index, IndexMut, Mul, Sub};
+use crate::{
+    error::{LinalgError, Result},
+    matrix::Matrix,
+    traits::Augment,
+};
+use num_traits::{Float, Num};
+use std::{
+    iter::{FromIterator, Sum},
+    ops::{Add, AddAssign, Div, DivAssign, Index, IndexMut, Mul, MulAssign, Neg, Sub, SubAssign},
+};
 
 /// Semantic alias for `Vector`. Represents single column vector of a 2D matrix.
 pub type Col<T> = Vector<T>;
@@ -9,9 +16,16 @@ pub type Col<T> = Vector<T>;
 pub type Row<T> = Vector<T>;
 
 #[derive(PartialEq, PartialOrd, Clone, Debug)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Deserialize),
+    serde(try_from = "VectorData<T>")
+)]
 /// A 1 dimensional, ordered collection of values which can represent
 /// either a row or a column of a matrix. Not typically used by application
 /// code. In most cases prefer [`Col`](type.Col.html) or [`Row`](type.Row.html).
+///
+/// With the `serde` feature, a `Vector` serializes as a sequence of its entries.
 pub struct Vector<T> {
     data: Vec<T>,
 }
@@ -43,6 +57,50 @@ where
         }
     }
 
+    /// Fallible version of [`new`](#method.new), returning `LinalgError::Empty`
+    /// rather than panicking if len ≦ 0.
+    pub fn try_new(len: usize) -> Result<Self> {
+        if len == 0 {
+            return Err(LinalgError::Empty);
+        }
+        Ok(Vector::new(len))
+    }
+
+    /// Fallible version of [`collect`](#impl-FromIterator<T>), returning
+    /// `LinalgError::Empty` rather than panicking if `iter` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{error::LinalgError, row, vector::{Row, Vector}};
+    ///
+    /// assert_eq!(Ok(row![1, 4, 9]), Vector::try_from_iter((1..4).map(|x| x * x)));
+    /// assert_eq!(Err(LinalgError::Empty), Vector::<i32>::try_from_iter(vec![]));
+    /// ```
+    pub fn try_from_iter<I: IntoIterator<Item = T>>(iter: I) -> Result<Self> {
+        let data: Vec<T> = iter.into_iter().collect();
+        if data.is_empty() {
+            return Err(LinalgError::Empty);
+        }
+        Ok(Vector { data })
+    }
+
+    /// Fallible version of [`sum`](#impl-Sum<Vector<T>>), returning
+    /// `LinalgError::Empty` rather than panicking if `iter` is empty, or an
+    /// error if the lengths differ.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{error::LinalgError, row, vector::{Row, Vector}};
+    ///
+    /// assert_eq!(Ok(row![4, 6]), Vector::try_sum(vec![row![1, 2], row![3, 4]]));
+    /// assert_eq!(Err(LinalgError::Empty), Vector::<i32>::try_sum(vec![]));
+    /// ```
+    pub fn try_sum<I: IntoIterator<Item = Vector<T>>>(iter: I) -> Result<Self> {
+        let mut iter = iter.into_iter();
+        let first = iter.next().ok_or(LinalgError::Empty)?;
+        iter.try_fold(first, |acc, v| acc.try_add(&v))
+    }
+
     /// Returns the number of elements in the vector, also referred to as its 'length'.
     ///
     /// # Example
@@ -91,7 +149,7 @@ where
     }
 
     /// Returns an Iterator<Item=T > over the elements of the Vector
-    pub fn iter(&self) -> std::slice::Iter<T> {
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
         self.data.iter()
     }
 
@@ -104,19 +162,127 @@ where
         }
     }
 
-    fn apply_operation_for_each<F>(&self, rhs: &Vector<T>, mut op: F) -> Vector<T>
+    /// Fallible version of [`Add`](#impl-Add<%26Vector<T>>), returning an error
+    /// rather than panicking if `rhs` is not the same length as `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{row, error::LinalgError, vector::Row};
+    ///
+    /// let a = row![1, 2, 3];
+    /// assert_eq!(Ok(row![2, 4, 6]), a.try_add(&a));
+    /// assert_eq!(
+    ///     Err(LinalgError::DimensionMismatch { expected: (3, 1), found: (2, 1) }),
+    ///     a.try_add(&row![1, 2])
+    /// );
+    /// ```
+    pub fn try_add(&self, rhs: &Vector<T>) -> Result<Vector<T>> {
+        self.try_apply_operation_for_each(rhs, Add::add)
+    }
+
+    /// Fallible version of [`Sub`](#impl-Sub<%26Vector<T>>), returning an error
+    /// rather than panicking if `rhs` is not the same length as `self`.
+    pub fn try_sub(&self, rhs: &Vector<T>) -> Result<Vector<T>> {
+        self.try_apply_operation_for_each(rhs, Sub::sub)
+    }
+
+    fn try_apply_operation_for_each<F>(&self, rhs: &Vector<T>, mut op: F) -> Result<Vector<T>>
     where
         F: FnMut(T, T) -> T,
     {
-        assert_eq!(self.data.len(), rhs.data.len());
-        Vector {
+        self.check_same_len(rhs)?;
+        Ok(Vector {
             data: self
                 .data
                 .iter()
                 .enumerate()
                 .map(|(i, x)| op(*x, rhs[i]))
                 .collect(),
+        })
+    }
+
+    /// Fallible version of [`dot`](#method.dot), returning an error rather than
+    /// panicking if `rhs` is not the same length as `self`.
+    pub fn try_dot(&self, rhs: &Vector<T>) -> Result<T> {
+        self.check_same_len(rhs)?;
+        Ok(self
+            .data
+            .iter()
+            .zip(rhs.data.iter())
+            .fold(T::zero(), |acc, (a, b)| acc + *a * *b))
+    }
+
+    /// The dot product a · b = Σ aᵢbᵢ.
+    ///
+    /// # Panics
+    /// Panics if `rhs` is not the same length as `self`.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{row, vector::Row};
+    ///
+    /// assert_eq!(32, row![1, 2, 3].dot(&row![4, 5, 6]));
+    /// ```
+    pub fn dot(&self, rhs: &Vector<T>) -> T {
+        self.try_dot(rhs).unwrap()
+    }
+
+    /// The outer product abᵀ, an m x n `Matrix` for a of length m and b of length n.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, row, matrix::Matrix, vector::Row};
+    ///
+    /// assert_eq!(mat![[3, 4], [6, 8]], row![1, 2].outer(&row![3, 4]));
+    /// ```
+    pub fn outer(&self, rhs: &Vector<T>) -> Matrix<T> {
+        let mut product = Matrix::new(self.len(), rhs.len());
+        for (i, a) in self.data.iter().enumerate() {
+            for (p, b) in product[i].iter_mut().zip(rhs.data.iter()) {
+                *p = *a * *b;
+            }
+        }
+        product
+    }
+
+    fn check_same_len(&self, rhs: &Vector<T>) -> Result<()> {
+        if self.data.len() != rhs.data.len() {
+            return Err(LinalgError::DimensionMismatch {
+                expected: (self.data.len(), 1),
+                found: (rhs.data.len(), 1),
+            });
         }
+        Ok(())
+    }
+}
+
+impl<T> Vector<T>
+where
+    T: Float,
+{
+    /// The L1 norm, Σ |xᵢ|.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{row, vector::Row};
+    ///
+    /// let x = row![3.0, -4.0];
+    /// assert_eq!(7.0, x.norm_1());
+    /// assert_eq!(5.0, x.norm_2());
+    /// assert_eq!(4.0, x.norm_inf());
+    /// ```
+    pub fn norm_1(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, x| acc + x.abs())
+    }
+
+    /// The L2, or Euclidean, norm √(Σ xᵢ²), accumulated without overflow.
+    pub fn norm_2(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, x| acc.hypot(*x))
+    }
+
+    /// The L∞ norm, max |xᵢ|.
+    pub fn norm_inf(&self) -> T {
+        self.data.iter().fold(T::zero(), |acc, x| acc.max(x.abs()))
     }
 }
 
@@ -155,8 +321,7 @@ where
     type Output = Vector<T>;
     /// Perform scalar multiplication for A * n
     /// where A is a Vector of <T>, and n is a scalar <T>.
-    /// Note: n must be the right-hand side of the equation.
-    /// **`n * A` will result in a compiler error**.
+    /// For `f64` and `f32` entries, `n * A` works too.
     ///
     /// # Example
     /// ```
@@ -224,7 +389,7 @@ where
     /// assert_eq!(row![20, 40, 60, 80], c);
     /// ```
     fn add(self, rhs: &Vector<T>) -> Self::Output {
-        self.apply_operation_for_each(rhs, Add::add)
+        self.try_add(rhs).unwrap()
     }
 }
 
@@ -247,7 +412,184 @@ where
     /// assert_eq!(row![0, 0, 0, 0], c);
     /// ```
     fn sub(self, rhs: &Vector<T>) -> Self::Output {
-        self.apply_operation_for_each(rhs, Sub::sub)
+        self.try_sub(rhs).unwrap()
+    }
+}
+
+forward_ref_binop!(impl Add, add for Vector, Vector, Vector);
+forward_ref_binop!(impl Sub, sub for Vector, Vector, Vector);
+
+impl<T> Mul<T> for Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+    fn mul(self, rhs: T) -> Self::Output {
+        &self * rhs
+    }
+}
+
+scalar_lhs_mul!(Vector: f64, f32);
+
+impl<T> Div<T> for Vector<T>
+where
+    T: Num + Copy,
+{
+    type Output = Vector<T>;
+    fn div(self, rhs: T) -> Self::Output {
+        &self / rhs
+    }
+}
+
+impl<T> Neg for &Vector<T>
+where
+    T: Num + Copy + Neg<Output = T>,
+{
+    type Output = Vector<T>;
+    fn neg(self) -> Self::Output {
+        self.apply_operation(T::zero(), |x, _| -x)
+    }
+}
+
+impl<T> Neg for Vector<T>
+where
+    T: Num + Copy + Neg<Output = T>,
+{
+    type Output = Vector<T>;
+    fn neg(self) -> Self::Output {
+        -&self
+    }
+}
+
+impl<T> AddAssign<&Vector<T>> for Vector<T>
+where
+    T: Num + Copy,
+{
+    /// # Panics
+    /// Panics if `rhs` is not the same length as `self`.
+    fn add_assign(&mut self, rhs: &Vector<T>) {
+        self.check_same_len(rhs).unwrap();
+        for (a, b) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *a = *a + *b;
+        }
+    }
+}
+
+impl<T> AddAssign<Vector<T>> for Vector<T>
+where
+    T: Num + Copy,
+{
+    fn add_assign(&mut self, rhs: Vector<T>) {
+        *self += &rhs;
+    }
+}
+
+impl<T> SubAssign<&Vector<T>> for Vector<T>
+where
+    T: Num + Copy,
+{
+    /// # Panics
+    /// Panics if `rhs` is not the same length as `self`.
+    fn sub_assign(&mut self, rhs: &Vector<T>) {
+        self.check_same_len(rhs).unwrap();
+        for (a, b) in self.data.iter_mut().zip(rhs.data.iter()) {
+            *a = *a - *b;
+        }
+    }
+}
+
+impl<T> SubAssign<Vector<T>> for Vector<T>
+where
+    T: Num + Copy,
+{
+    fn sub_assign(&mut self, rhs: Vector<T>) {
+        *self -= &rhs;
+    }
+}
+
+impl<T> MulAssign<T> for Vector<T>
+where
+    T: Num + Copy,
+{
+    fn mul_assign(&mut self, rhs: T) {
+        self.data.iter_mut().for_each(|v| *v = *v * rhs);
+    }
+}
+
+impl<T> Sum for Vector<T>
+where
+    T: Num + Copy,
+{
+    /// Add up `Vector`s of the same length.
+    ///
+    /// # Panics
+    /// Panics if the iterator is empty, or the lengths differ. See
+    /// [`try_sum`](struct.Vector.html#method.try_sum) for input which may be.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{row, vector::{Row, Vector}};
+    ///
+    /// let total: Vector<i32> = vec![row![1, 2], row![3, 4], row![5, 6]].into_iter().sum();
+    /// assert_eq!(row![9, 12], total);
+    /// ```
+    fn sum<I: Iterator<Item = Vector<T>>>(mut iter: I) -> Self {
+        let first = iter
+            .next()
+            .expect("cannot sum an empty iterator of vectors");
+        iter.fold(first, |mut acc, v| {
+            acc += &v;
+            acc
+        })
+    }
+}
+
+impl<'a, T> Sum<&'a Vector<T>> for Vector<T>
+where
+    T: Num + Copy,
+{
+    /// # Panics
+    /// Panics if the iterator is empty, or the lengths differ. See
+    /// [`try_sum`](struct.Vector.html#method.try_sum) for input which may be.
+    fn sum<I: Iterator<Item = &'a Vector<T>>>(iter: I) -> Self {
+        iter.cloned().sum()
+    }
+}
+
+impl<T> FromIterator<T> for Vector<T>
+where
+    T: Num + Copy,
+{
+    /// # Panics
+    /// Panics if the iterator is empty. See
+    /// [`try_from_iter`](struct.Vector.html#method.try_from_iter) for input
+    /// which may be.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{row, vector::{Row, Vector}};
+    ///
+    /// let squares: Vector<u32> = (1..4).map(|x| x * x).collect();
+    /// assert_eq!(row![1, 4, 9], squares);
+    /// ```
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Vector::from(iter.into_iter().collect::<Vec<T>>())
+    }
+}
+
+impl<T> IntoIterator for Vector<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.into_iter()
+    }
+}
+
+impl<'a, T> IntoIterator for &'a Vector<T> {
+    type Item = &'a T;
+    type IntoIter = std::slice::Iter<'a, T>;
+    fn into_iter(self) -> Self::IntoIter {
+        self.data.iter()
     }
 }
 
@@ -298,6 +640,36 @@ where
     }
 }
 
+#[cfg(feature = "serde")]
+impl<T> serde::Serialize for Vector<T>
+where
+    T: serde::Serialize,
+{
+    fn serialize<S>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.data.serialize(serializer)
+    }
+}
+
+// A `Vector` as it was serialized, before it has been checked for entries.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(transparent)]
+struct VectorData<T>(Vec<T>);
+
+#[cfg(feature = "serde")]
+impl<T> std::convert::TryFrom<VectorData<T>> for Vector<T> {
+    type Error = LinalgError;
+    fn try_from(v: VectorData<T>) -> Result<Self> {
+        if v.0.is_empty() {
+            return Err(LinalgError::Empty);
+        }
+        Ok(Vector { data: v.0 })
+    }
+}
+
 impl<T> From<Vec<T>> for Vector<T>
 where
     T: Num + Copy,
@@ -376,4 +748,81 @@ mod tests {
         let sut = row![100, 100, 100, 100];
         assert_eq!((3, 100), sut.max_at());
     }
+
+    #[test]
+    fn test_owned_and_assigning_operators() {
+        let a = row![1, 2, 3];
+        let b = row![4, 5, 6];
+
+        assert_eq!(row![5, 7, 9], a.clone() + b.clone());
+        assert_eq!(row![-3, -3, -3], &a - b.clone());
+        assert_eq!(row![-1, -2, -3], -a.clone());
+
+        let mut c = a.clone();
+        c += &b;
+        c -= a.clone();
+        c *= 2;
+        assert_eq!(row![8, 10, 12], c);
+        assert_eq!(row![2.0, 4.0], 2.0 * row![1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_dot_and_outer() {
+        let a = row![1.0, 2.0];
+        assert_eq!(5.0, a.dot(&a));
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 1),
+                found: (1, 1)
+            }),
+            a.try_dot(&row![1.0])
+        );
+
+        let outer = a.outer(&row![1.0, 0.0, -1.0]);
+        assert_eq!((2, 3), (outer.rows, outer.cols));
+        assert_eq!(-2.0, outer[1][2]);
+    }
+
+    #[test]
+    fn test_iterator_traits() {
+        let vs = [row![1, 1], row![2, 2]];
+        assert_eq!(row![3, 3], vs.iter().sum());
+
+        let doubled: Vector<i32> = vs[1].iter().map(|x| x * 2).collect();
+        assert_eq!(row![4, 4], doubled);
+
+        let mut total = 0;
+        for x in &doubled {
+            total += x;
+        }
+        assert_eq!(8, total);
+        assert_eq!(vec![4, 4], doubled.into_iter().collect::<Vec<i32>>());
+    }
+
+    #[test]
+    fn test_fallible_iterator_traits() {
+        let empty: Vec<Vector<i32>> = vec![];
+        assert_eq!(Err(LinalgError::Empty), Vector::try_sum(empty));
+        assert_eq!(
+            Err(LinalgError::DimensionMismatch {
+                expected: (2, 1),
+                found: (3, 1)
+            }),
+            Vector::try_sum(vec![row![1, 2], row![1, 2, 3]])
+        );
+        assert_eq!(
+            Err(LinalgError::Empty),
+            Vector::try_from_iter((0..0).map(|x: i32| x * 2))
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let v = row![1.0, -2.5, 3.0];
+        let json = serde_json::to_string(&v).unwrap();
+        assert_eq!("[1.0,-2.5,3.0]", json);
+        assert_eq!(v, serde_json::from_str(&json).unwrap());
+        assert!(serde_json::from_str::<Vector<f64>>("[]").is_err());
+    }
 }
@@ -0,0 +1,399 @@
+use crate::matrix::Matrix;
+use num_traits::Num;
+use std::ops::{Bound, Index, IndexMut, Range, RangeBounds};
+
+/// Iterator over the entries of one column of a `Matrix` or view, top to bottom.
+pub type ColIter<'a, T> = std::iter::StepBy<std::slice::Iter<'a, T>>;
+
+/// A borrowed, rectangular block of a `Matrix`, made without copying.
+///
+/// Rows of the view are slices into the parent's buffer. Create one with
+/// [`Matrix::view`](../matrix/struct.Matrix.html#method.view).
+#[derive(Debug, Clone, Copy)]
+pub struct MatrixView<'a, T> {
+    /// Number of rows in the view
+    pub rows: usize,
+    /// Number of columns in the view
+    pub cols: usize,
+    // Distance between the starts of consecutive rows, the parent's width.
+    stride: usize,
+    // From the first entry of the view to its last.
+    data: &'a [T],
+}
+
+/// A mutably borrowed, rectangular block of a `Matrix`, made without copying.
+///
+/// Create one with [`Matrix::view_mut`](../matrix/struct.Matrix.html#method.view_mut).
+#[derive(Debug)]
+pub struct MatrixViewMut<'a, T> {
+    /// Number of rows in the view
+    pub rows: usize,
+    /// Number of columns in the view
+    pub cols: usize,
+    stride: usize,
+    data: &'a mut [T],
+}
+
+impl<T> Matrix<T>
+where
+    T: Num + Copy,
+{
+    /// Borrow the block of rows `rows` and columns `cols` as a `MatrixView`.
+    ///
+    /// # Panics
+    /// Panics if either range is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let a = mat![[1, 2, 3], [4, 5, 6], [7, 8, 9]];
+    /// let v = a.view(1.., ..2);
+    ///
+    /// assert_eq!((2, 2), (v.rows, v.cols));
+    /// assert_eq!(8, v[(1, 1)]);
+    /// assert_eq!(mat![[4, 5], [7, 8]], v.to_matrix());
+    /// ```
+    pub fn view<R, C>(&self, rows: R, cols: C) -> MatrixView<'_, T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (rows, cols) = (resolve(rows, self.rows), resolve(cols, self.cols));
+        let span = span(&rows, &cols, self.cols);
+        MatrixView {
+            rows: rows.len(),
+            cols: cols.len(),
+            stride: self.cols,
+            data: &self.as_slice()[span],
+        }
+    }
+
+    /// Mutably borrow the block of rows `rows` and columns `cols` as a `MatrixViewMut`.
+    ///
+    /// # Panics
+    /// Panics if either range is out of bounds.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let mut a = mat![[1, 2, 3], [4, 5, 6]];
+    /// a.view_mut(.., 1..).fill(0);
+    ///
+    /// assert_eq!(mat![[1, 0, 0], [4, 0, 0]], a);
+    /// ```
+    pub fn view_mut<R, C>(&mut self, rows: R, cols: C) -> MatrixViewMut<'_, T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (rows, cols) = (resolve(rows, self.rows), resolve(cols, self.cols));
+        let span = span(&rows, &cols, self.cols);
+        let stride = self.cols;
+        MatrixViewMut {
+            rows: rows.len(),
+            cols: cols.len(),
+            stride,
+            data: &mut self.as_mut_slice()[span],
+        }
+    }
+}
+
+impl<'a, T> MatrixView<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Row `i` of the view.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    pub fn row(&self, i: usize) -> &'a [T] {
+        assert!(
+            i < self.rows,
+            "row {} out of bounds for {} rows",
+            i,
+            self.rows
+        );
+        &self.data[row_range(i, self.stride, self.cols)]
+    }
+
+    /// Returns an iterator over the rows of the view, each as a slice.
+    pub fn rows(&self) -> impl Iterator<Item = &'a [T]> + 'a {
+        let (data, stride, cols) = (self.data, self.stride, self.cols);
+        (0..self.rows).map(move |i| &data[row_range(i, stride, cols)])
+    }
+
+    /// Returns an iterator over the columns of the view, each as an iterator
+    /// over its entries.
+    pub fn cols(&self) -> impl Iterator<Item = ColIter<'a, T>> + 'a {
+        let (data, stride) = (self.data, self.stride);
+        (0..self.cols).map(move |j| data.get(j..).unwrap_or(&[]).iter().step_by(stride))
+    }
+
+    /// Returns an iterator over ((row, col), entry) in row-major order.
+    pub fn indexed_iter(&self) -> impl Iterator<Item = ((usize, usize), &'a T)> + 'a {
+        self.rows().enumerate().flat_map(|(i, row)| {
+            row.iter()
+                .enumerate()
+                .map(move |(j, entry)| ((i, j), entry))
+        })
+    }
+
+    /// Borrow a block of this view, with ranges relative to the view.
+    ///
+    /// # Panics
+    /// Panics if either range is out of bounds.
+    pub fn view<R, C>(&self, rows: R, cols: C) -> MatrixView<'a, T>
+    where
+        R: RangeBounds<usize>,
+        C: RangeBounds<usize>,
+    {
+        let (rows, cols) = (resolve(rows, self.rows), resolve(cols, self.cols));
+        MatrixView {
+            rows: rows.len(),
+            cols: cols.len(),
+            stride: self.stride,
+            data: &self.data[span(&rows, &cols, self.stride)],
+        }
+    }
+
+    /// Copy the view into a new `Matrix`.
+    ///
+    /// # Panics
+    /// Panics if the view is empty.
+    pub fn to_matrix(&self) -> Matrix<T> {
+        Matrix::from_fn(self.rows, self.cols, |i, j| self[(i, j)])
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for MatrixView<'a, T>
+where
+    T: Num + Copy,
+{
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        &self.row(i)[j]
+    }
+}
+
+impl<'a, T> MatrixViewMut<'a, T>
+where
+    T: Num + Copy,
+{
+    /// Reborrow as a read-only `MatrixView`.
+    pub fn as_view(&self) -> MatrixView<'_, T> {
+        MatrixView {
+            rows: self.rows,
+            cols: self.cols,
+            stride: self.stride,
+            data: self.data,
+        }
+    }
+
+    /// Row `i` of the view, for writing.
+    ///
+    /// # Panics
+    /// Panics if `i` is out of bounds.
+    pub fn row_mut(&mut self, i: usize) -> &mut [T] {
+        assert!(
+            i < self.rows,
+            "row {} out of bounds for {} rows",
+            i,
+            self.rows
+        );
+        &mut self.data[row_range(i, self.stride, self.cols)]
+    }
+
+    /// Returns an iterator over the rows of the view, each as a mutable slice.
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = &mut [T]> {
+        let cols = self.cols;
+        // A view with no columns borrows no data, but still has its rows.
+        self.data
+            .chunks_mut(self.stride)
+            .map(move |row| &mut row[..cols])
+            .chain(std::iter::repeat_with(|| &mut [][..]))
+            .take(self.rows)
+    }
+
+    /// Returns an iterator over ((row, col), entry) in row-major order, with
+    /// each entry mutable.
+    pub fn indexed_iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.rows_mut().enumerate().flat_map(|(i, row)| {
+            row.iter_mut()
+                .enumerate()
+                .map(move |(j, entry)| ((i, j), entry))
+        })
+    }
+
+    /// Set every entry of the view to `value`.
+    pub fn fill(&mut self, value: T) {
+        self.rows_mut()
+            .for_each(|row| row.iter_mut().for_each(|x| *x = value));
+    }
+
+    /// Overwrite the view with the entries of `src`.
+    ///
+    /// # Panics
+    /// Panics if `src` is not the same shape as the view.
+    ///
+    /// # Example
+    /// ```
+    /// use launearalg::{mat, matrix::Matrix};
+    ///
+    /// let mut a = Matrix::new(3, 3);
+    /// let b = mat![[1, 2], [3, 4]];
+    /// a.view_mut(1.., 1..).copy_from(&b.view(.., ..));
+    ///
+    /// assert_eq!(mat![[0, 0, 0], [0, 1, 2], [0, 3, 4]], a);
+    /// ```
+    pub fn copy_from(&mut self, src: &MatrixView<'_, T>) {
+        assert_eq!((self.rows, self.cols), (src.rows, src.cols));
+        for (dst, src) in self.rows_mut().zip(src.rows()) {
+            dst.copy_from_slice(src);
+        }
+    }
+}
+
+impl<'a, T> Index<(usize, usize)> for MatrixViewMut<'a, T>
+where
+    T: Num + Copy,
+{
+    type Output = T;
+    fn index(&self, (i, j): (usize, usize)) -> &Self::Output {
+        assert!(
+            i < self.rows,
+            "row {} out of bounds for {} rows",
+            i,
+            self.rows
+        );
+        &self.data[row_range(i, self.stride, self.cols)][j]
+    }
+}
+
+impl<'a, T> IndexMut<(usize, usize)> for MatrixViewMut<'a, T>
+where
+    T: Num + Copy,
+{
+    fn index_mut(&mut self, (i, j): (usize, usize)) -> &mut Self::Output {
+        &mut self.row_mut(i)[j]
+    }
+}
+
+fn resolve<R>(range: R, len: usize) -> Range<usize>
+where
+    R: RangeBounds<usize>,
+{
+    let start = match range.start_bound() {
+        Bound::Included(s) => *s,
+        Bound::Excluded(s) => s + 1,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(e) => e + 1,
+        Bound::Excluded(e) => *e,
+        Bound::Unbounded => len,
+    };
+    assert!(
+        start <= end && end <= len,
+        "range {}..{} out of bounds for length {}",
+        start,
+        end,
+        len
+    );
+    start..end
+}
+
+// Row `i` of a view's data, empty when the view has no columns and so no data.
+fn row_range(i: usize, stride: usize, cols: usize) -> Range<usize> {
+    if cols == 0 {
+        return 0..0;
+    }
+    i * stride..i * stride + cols
+}
+
+// The span of a row-major buffer of width `stride` from entry (rows.start,
+// cols.start) to entry (rows.end - 1, cols.end - 1).
+fn span(rows: &Range<usize>, cols: &Range<usize>, stride: usize) -> Range<usize> {
+    if rows.is_empty() || cols.is_empty() {
+        return 0..0;
+    }
+    rows.start * stride + cols.start..(rows.end - 1) * stride + cols.end
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{mat, matrix::Matrix};
+
+    #[test]
+    fn test_nested_views_and_iterators() {
+        let a = Matrix::from_fn(4, 5, |i, j| 10 * i + j);
+        let v = a.view(1..4, 1..5).view(1.., ..=1);
+
+        assert_eq!(mat![[21, 22], [31, 32]], v.to_matrix());
+        assert_eq!(
+            vec![&[21, 22][..], &[31, 32][..]],
+            v.rows().collect::<Vec<_>>()
+        );
+        assert_eq!(
+            vec![vec![22, 32]],
+            v.cols()
+                .skip(1)
+                .map(|c| c.copied().collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            Some(((1, 0), &31)),
+            v.indexed_iter().find(|(_, x)| **x > 30)
+        );
+    }
+
+    #[test]
+    fn test_empty_view() {
+        let a = mat![[1, 2], [3, 4]];
+        let v = a.view(2.., ..);
+        assert_eq!((0, 2), (v.rows, v.cols));
+        assert_eq!(0, v.indexed_iter().count());
+        assert!(v.cols().all(|mut c| c.next().is_none()));
+    }
+
+    #[test]
+    fn test_zero_width_view() {
+        let mut a = mat![[1.0, 2.0], [3.0, 4.0]];
+        let v = a.view(.., 1..1);
+        assert_eq!((2, 0), (v.rows, v.cols));
+        assert_eq!(2, v.rows().count());
+        assert!(v.rows().all(|row| row.is_empty()));
+        assert!(v.row(1).is_empty());
+        assert_eq!(0, v.indexed_iter().count());
+        assert_eq!(0, v.cols().count());
+
+        let mut v = a.view_mut(.., 2..);
+        assert_eq!(2, v.rows_mut().count());
+        assert!(v.row_mut(1).is_empty());
+        assert_eq!(0, v.indexed_iter_mut().count());
+        v.fill(0.0);
+        assert_eq!(mat![[1.0, 2.0], [3.0, 4.0]], a);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_view_out_of_bounds_panic() {
+        let a = mat![[1, 2], [3, 4]];
+        a.view(0..3, ..);
+    }
+
+    #[test]
+    fn test_view_mut_writes_through() {
+        let mut a = Matrix::new(3, 4);
+        {
+            let mut v = a.view_mut(1..3, 2..4);
+            for ((i, j), x) in v.indexed_iter_mut() {
+                *x = 10 * i + j + 1;
+            }
+            v[(0, 0)] = 100;
+            assert_eq!(2, v.as_view()[(0, 1)]);
+        }
+        assert_eq!(mat![[0, 0, 0, 0], [0, 0, 100, 2], [0, 0, 11, 12]], a);
+    }
+}